@@ -1,7 +1,7 @@
-use modern_chess::logic::{Game, GamePhase, GameTransition};
+use modern_chess::logic::{Board, Game, GamePhase, GameTransition, Ply, Puzzle, PuzzleStatus};
 use modern_chess::shared::Position;
-use modern_chess::specs::parse_game_spec;
 use modern_chess::specs::GameSpecError;
+use modern_chess::specs::{parse_game_spec, parse_puzzles};
 use std::env;
 use std::io::{self, Write};
 
@@ -14,14 +14,30 @@ const PLAYER_COLORS: &[&str] = &[
     "\x1b[32m", // green
     "\x1b[36m", // cyan
 ];
+// Neutral pieces (e.g. the duck) belong to no player slot.
+const NEUTRAL_COLOR: &str = "\x1b[90m"; // grey
 const RESET: &str = "\x1b[0m";
 
+// Hex board files, following Glinski's notation (no "j").
+const HEX_FILES: &str = "abcdefghiklmnopqrstuvwxyz";
+
 const DEFAULT_SPEC: &str = concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/../../crates/engine/specs/chess.json"
 );
 
+// Puzzles name their game spec, which is looked up here as `<name>.json`.
+const SPECS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../crates/engine/specs");
+
 fn main() -> Result<(), GameSpecError> {
+    if env::args().nth(1).as_deref() == Some("--puzzles") {
+        let Some(puzzles_path) = env::args().nth(2) else {
+            println!("Usage: modern-chess-cli --puzzles <file>");
+            return Ok(());
+        };
+        return run_puzzles(&puzzles_path);
+    }
+
     let spec_path = env::args().nth(1).unwrap_or(DEFAULT_SPEC.to_string());
     println!("Loading spec: {}", spec_path);
 
@@ -42,7 +58,7 @@ fn play_game(game: &mut Game) {
                 let current_player = game.current_player();
                 println!("Current player: {}", current_player);
 
-                if let Some(position) = get_piece_selection() {
+                if let Some(position) = get_piece_selection(&game.board) {
                     game.transition(GameTransition::CalculateMoves { position })
                         .unwrap_or_else(|err| println!("Error: {:?}", err));
                 }
@@ -64,7 +80,7 @@ fn play_game(game: &mut Game) {
                     );
                 }
 
-                if let Some(target) = get_move_selection() {
+                if let Some(target) = get_move_selection(&game.board) {
                     game.transition(GameTransition::ExecuteMove { position: target })
                         .unwrap_or_else(|err| println!("Error: {:?}", err));
                 }
@@ -91,11 +107,51 @@ fn play_game(game: &mut Game) {
                     println!("{:?}", sq);
                 }
 
-                if let Some(target) = get_move_selection() {
+                if let Some(target) = get_move_selection(&game.board) {
                     game.transition(GameTransition::ExecuteDrop { position: target })
                         .unwrap_or_else(|err| println!("Error: {:?}", err));
                 }
             }
+            GamePhase::Placing { piece_code } => {
+                print_board(game);
+                println!("{} must place the {}.", game.current_player(), piece_code);
+
+                if let Some(target) = get_placement_selection(&game.board) {
+                    game.transition(GameTransition::Place { position: target })
+                        .unwrap_or_else(|err| println!("Error: {:?}", err));
+                }
+            }
+            GamePhase::Continuing {
+                position,
+                mandatory,
+                ..
+            } => {
+                let (position, mandatory) = (position.clone(), *mandatory);
+                print_board(game);
+                println!(
+                    "{} continues with the piece on {:?}.",
+                    game.current_player(),
+                    position
+                );
+
+                game.transition(GameTransition::CalculateMoves { position })
+                    .unwrap_or_else(|err| println!("Error: {:?}", err));
+                if let Some(valid_moves) = &game.state.available_moves {
+                    for (valid_move, effect) in valid_moves {
+                        println!("{:?}: {:?}", valid_move, effect.action);
+                    }
+                }
+
+                match get_continuation_selection(&game.board, mandatory) {
+                    Some(target) => game
+                        .transition(GameTransition::ExecuteMove { position: target })
+                        .unwrap_or_else(|err| println!("Error: {:?}", err)),
+                    None if !mandatory => game
+                        .transition(GameTransition::StopChain)
+                        .unwrap_or_else(|err| println!("Error: {:?}", err)),
+                    None => (),
+                }
+            }
             GamePhase::GameOver { winner } => {
                 print_board(game);
                 match winner {
@@ -108,8 +164,93 @@ fn play_game(game: &mut Game) {
     }
 }
 
+fn run_puzzles(puzzles_path: &str) -> Result<(), GameSpecError> {
+    println!("Loading puzzles: {}", puzzles_path);
+    let puzzles = parse_puzzles(puzzles_path)?;
+    let mut solved = 0;
+
+    for (index, puzzle_spec) in puzzles.iter().enumerate() {
+        println!();
+        println!(
+            "Puzzle {}/{}: {} ({})",
+            index + 1,
+            puzzles.len(),
+            puzzle_spec.name,
+            puzzle_spec.spec
+        );
+
+        let game_spec = parse_game_spec(format!("{}/{}.json", SPECS_DIR, puzzle_spec.spec))?;
+        let mut puzzle = match Puzzle::new(puzzle_spec.clone(), Game::from_spec(game_spec)) {
+            Ok(puzzle) => puzzle,
+            Err(err) => {
+                println!("Skipping invalid puzzle: {:?}", err);
+                continue;
+            }
+        };
+
+        if play_puzzle(&mut puzzle) {
+            solved += 1;
+        }
+    }
+
+    println!();
+    println!("Solved {}/{} puzzles.", solved, puzzles.len());
+    Ok(())
+}
+
+/// Runs one puzzle to completion; returns whether it was solved.
+fn play_puzzle(puzzle: &mut Puzzle) -> bool {
+    loop {
+        print_board(&puzzle.game);
+        if let Some(hand) = puzzle.game.state.hand.get(&puzzle.spec.to_move) {
+            println!("Hand: {:?}", hand);
+        }
+        println!("{} to play.", puzzle.spec.to_move);
+
+        let Some(ply) = get_ply_selection(&puzzle.game.board) else {
+            match puzzle.hint() {
+                Some(hint) => println!("Hint: {:?}", hint),
+                None => println!("No hint available."),
+            }
+            continue;
+        };
+
+        match puzzle.submit(&ply) {
+            Ok(PuzzleStatus::InProgress) => println!("Correct! Keep going."),
+            Ok(PuzzleStatus::Solved) => {
+                print_board(&puzzle.game);
+                println!("Solved!");
+                return true;
+            }
+            Ok(PuzzleStatus::Failed) => {
+                println!("Wrong move. Expected: {:?}", puzzle.hint());
+                return false;
+            }
+            Err(err) => println!("Error: {:?}", err),
+        }
+    }
+}
+
 fn print_board(game: &Game) {
     let board = &game.board;
+
+    // Print legend
+    let legend: Vec<String> = game
+        .players
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let color = PLAYER_COLORS[i % PLAYER_COLORS.len()];
+            format!("{}[{}]{}", color, name, RESET)
+        })
+        .collect();
+    println!("Players: {}", legend.join("  "));
+
+    if board.is_hex() {
+        print_hex_board(game);
+        return;
+    }
+
     let cols = board.dimensions[0] as usize;
     let rows = board.dimensions[1] as usize;
 
@@ -127,66 +268,157 @@ fn print_board(game: &Game) {
         (0..cols).map(|i| format!("{:<4}", i)).collect::<String>()
     );
 
-    // Print legend
-    let legend: Vec<String> = game
-        .players
-        .iter()
-        .enumerate()
-        .map(|(i, name)| {
-            let color = PLAYER_COLORS[i % PLAYER_COLORS.len()];
-            format!("{}[{}]{}", color, name, RESET)
-        })
-        .collect();
-    println!("Players: {}", legend.join("  "));
+    // Boards with more than two dimensions are drawn as a stack of 2-D layers, one per value
+    // of the extra coordinates, with the highest layer on top.
+    for layer in board_layers(&board.dimensions[2..]).iter().rev() {
+        if !layer.is_empty() {
+            println!("Layer {:?}:", layer);
+        }
+        println!("{}", col_header);
+        println!("{}{}", sep_prefix, separator);
+
+        for j in (0..rows).rev() {
+            let row_prefix = format!("{:>2} ", j);
+            let mut row = String::from("|");
 
-    println!("{}", col_header);
-    println!("{}{}", sep_prefix, separator);
-
-    for j in (0..rows).rev() {
-        let row_prefix = format!("{:>2} ", j);
-        let mut row = String::from("|");
-
-        for i in 0..cols {
-            let position = vec![i as u8, j as u8];
-
-            match game.piece_at_position(&position) {
-                Some(piece) => {
-                    let player_index = game
-                        .players
-                        .iter()
-                        .position(|p| p == &piece.player)
-                        .unwrap_or(0);
-                    let color = PLAYER_COLORS[player_index % PLAYER_COLORS.len()];
-                    row.push_str(&format!("{}{}{}", color, &piece.code[..3], RESET));
+            for i in 0..cols {
+                let mut position = vec![i as u8, j as u8];
+                position.extend(layer);
+
+                match cell_label(game, &position) {
+                    Some(label) => row.push_str(&label),
+                    None if game.state.disabled_positions.contains(&position) => {
+                        row.push_str("###")
+                    }
+                    None => row.push_str("..."),
                 }
-                None => row.push_str("..."),
+
+                row.push('|');
+            }
+            println!("{}{}", row_prefix, row);
+            println!("{}{}", sep_prefix, separator);
+        }
+
+        println!("{}", col_header);
+    }
+}
+
+/// Every combination of the extra coordinates (beyond x and y), last coordinate varying fastest.
+/// A 2-D board has a single, empty layer.
+fn board_layers(extra_dimensions: &[u8]) -> Vec<Vec<u8>> {
+    extra_dimensions.iter().fold(vec![vec![]], |layers, &size| {
+        layers
+            .into_iter()
+            .flat_map(|prefix| {
+                (0..size).map(move |coordinate| {
+                    let mut layer = prefix.clone();
+                    layer.push(coordinate);
+                    layer
+                })
+            })
+            .collect()
+    })
+}
+
+/// Draws a hex board with files as columns. Cells of neighbouring files sit half a line apart,
+/// so each printed line holds every other file; empty cells show their rank.
+fn print_hex_board(game: &Game) {
+    let board = &game.board;
+    let size = board.dimensions[0] as i16;
+    let files: String = HEX_FILES.chars().take(size as usize).collect();
+    let header = format!(
+        " {}",
+        files
+            .chars()
+            .map(|f| format!("{:^4}", f))
+            .collect::<String>()
+    );
+
+    println!("{}", header);
+    // Cell [q, r] is drawn on line 2r + q, counting up from the bottom.
+    for line in (0..3 * size).rev() {
+        let mut row = String::new();
+        for q in 0..size {
+            let r = (line - q) / 2;
+            let position = vec![q, r];
+            if (line - q) % 2 != 0 || line < q || !game.is_position_valid(&position) {
+                row.push_str("    ");
+                continue;
             }
 
-            row.push('|');
+            let position: Position = vec![q as u8, r as u8];
+            let label = cell_label(game, &position)
+                .unwrap_or_else(|| format!("{:^3}", hex_rank(board, &position)));
+            row.push(' ');
+            row.push_str(&label);
+        }
+        if !row.trim().is_empty() {
+            println!("{}", row.trim_end());
         }
-        println!("{}{}", row_prefix, row);
-        println!("{}{}", sep_prefix, separator);
     }
+    println!("{}", header);
+}
+
+/// The piece standing on `position`, drawn as its colored 3-letter code.
+/// Pieces outside the player list (neutral ones) are drawn in grey.
+fn cell_label(game: &Game, position: &Position) -> Option<String> {
+    let piece = game.piece_at_position(position)?;
+    let color = match game.players.iter().position(|p| p == &piece.player) {
+        Some(index) => PLAYER_COLORS[index % PLAYER_COLORS.len()],
+        None => NEUTRAL_COLOR,
+    };
+    Some(format!("{}{}{}", color, &piece.code[..3], RESET))
+}
 
-    println!("{}", col_header);
+/// Lowest `r` on file `q` of a hex board.
+fn hex_first_row(board: &Board, q: u8) -> u8 {
+    let n = (board.dimensions[0] - 1) / 2;
+    n.saturating_sub(q)
 }
 
-fn get_piece_selection() -> Option<Position> {
-    print!("Select position to see available moves (e.g., [0, 1]): ");
+/// Rank of a hex cell, counted from 1 at the bottom of its file.
+fn hex_rank(board: &Board, position: &Position) -> u8 {
+    position[1] - hex_first_row(board, position[0]) + 1
+}
+
+fn get_piece_selection(board: &Board) -> Option<Position> {
+    print!("Select position to see available moves (e.g., [0, 1] or [0, 1, 0]): ");
     io::stdout().flush().unwrap();
 
     let mut input = String::new();
     io::stdin().read_line(&mut input).unwrap();
-    parse_position(&input.trim())
+    parse_position(board, input.trim())
 }
 
-fn get_move_selection() -> Option<Position> {
+fn get_move_selection(board: &Board) -> Option<Position> {
     print!("Select move to execute (e.g., [0, 1]): ");
     io::stdout().flush().unwrap();
 
     let mut input = String::new();
     io::stdin().read_line(&mut input).unwrap();
-    parse_position(&input.trim())
+    parse_position(board, input.trim())
+}
+
+fn get_placement_selection(board: &Board) -> Option<Position> {
+    print!("Select an empty square (e.g., [0, 1]): ");
+    io::stdout().flush().unwrap();
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    parse_position(board, input.trim())
+}
+
+fn get_continuation_selection(board: &Board, mandatory: bool) -> Option<Position> {
+    if mandatory {
+        print!("Select next move (e.g., [0, 1]): ");
+    } else {
+        print!("Select next move (e.g., [0, 1]) or blank to stop: ");
+    }
+    io::stdout().flush().unwrap();
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    parse_position(board, input.trim())
 }
 
 fn get_option_selection(options: Vec<String>) -> Option<String> {
@@ -198,7 +430,51 @@ fn get_option_selection(options: Vec<String>) -> Option<String> {
     Some(input.trim().to_string())
 }
 
-fn parse_position(input: &str) -> Option<Position> {
+/// Reads a ply: "x,y x,y [PROMOTION]" for moves, "CODE x,y" for drops, "x,y" for placements
+/// or "STOP x,y" to end a multi-leg move.
+/// Blank or unparseable input returns `None`, which asks for a hint.
+fn get_ply_selection(board: &Board) -> Option<Ply> {
+    print!("Enter move (e.g., 4,1 4,3), drop (e.g., GOLD 4,7) or blank for a hint: ");
+    io::stdout().flush().unwrap();
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    parse_ply(board, input.trim())
+}
+
+fn parse_ply(board: &Board, input: &str) -> Option<Ply> {
+    let parts: Vec<&str> = input.split_whitespace().collect();
+    match parts.as_slice() {
+        [keyword, at] if keyword.eq_ignore_ascii_case("stop") => Some(Ply::Stop {
+            at: parse_position(board, at)?,
+        }),
+        [from, to, rest @ ..] if rest.len() <= 1 => match parse_position(board, from) {
+            Some(from) => Some(Ply::Move {
+                from,
+                to: parse_position(board, to)?,
+                promotion: rest.first().map(|p| p.to_uppercase()),
+            }),
+            None if rest.is_empty() => Some(Ply::Drop {
+                piece_code: from.to_uppercase(),
+                to: parse_position(board, to)?,
+            }),
+            None => None,
+        },
+        [to] => Some(Ply::Place {
+            to: parse_position(board, to)?,
+        }),
+        _ => None,
+    }
+}
+
+/// Parses "[x, y]" / "x,y", or on hex boards also Glinski notation such as "f6".
+fn parse_position(board: &Board, input: &str) -> Option<Position> {
+    if board.is_hex() {
+        if let Some(position) = parse_hex_cell(board, input) {
+            return Some(position);
+        }
+    }
+
     let trimmed = input.trim_matches(|c| c == '[' || c == ']');
     let parts: Vec<&str> = trimmed.split(',').collect();
     // Reject blank input.
@@ -208,3 +484,15 @@ fn parse_position(input: &str) -> Option<Position> {
     // All parts must parse successfully; any failure returns None.
     parts.iter().map(|s| s.trim().parse::<u8>().ok()).collect()
 }
+
+/// Parses a file letter followed by a rank, e.g. "f6" → `[5, 5]`.
+fn parse_hex_cell(board: &Board, input: &str) -> Option<Position> {
+    let mut chars = input.chars();
+    let q = HEX_FILES.find(chars.next()?.to_ascii_lowercase())? as u8;
+    let rank = chars.as_str().parse::<u8>().ok()?;
+    let r = (hex_first_row(board, q) + rank).checked_sub(1)?;
+
+    let position = vec![q, r];
+    let extended: Vec<i16> = position.iter().map(|&c| c as i16).collect();
+    board.contains(&extended).then_some(position)
+}
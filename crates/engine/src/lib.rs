@@ -1,4 +1,5 @@
 pub mod logic;
+pub mod search;
 pub mod shared;
 pub mod specs;
 pub mod tests;
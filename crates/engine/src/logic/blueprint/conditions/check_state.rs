@@ -10,11 +10,11 @@ pub fn eval(condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
         return false;
     };
 
-    let abs = helpers::absolute_position(ctx.source_position, offset);
-    if !ctx.game.board.is_position_valid(&abs) {
+    let Some(abs) = ctx.game.offset(ctx.source_position, offset) else {
         return false;
-    }
+    };
 
-    helpers::piece_at_absolute(ctx.game, &abs)
+    ctx.game
+        .piece_at_position(&abs)
         .map_or(false, |piece| piece.state.contains_key(state_name.as_str()))
 }
@@ -0,0 +1,26 @@
+use crate::logic::blueprint::move_blueprint::Condition;
+
+use super::{context::ConditionEvalContext, helpers};
+
+/// Compares the counter named in `state` against [min, max]. Reads the piece at the relative
+/// `position` when given (failing on an empty square), otherwise the moving piece.
+pub fn eval(condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
+    let Some(name) = &condition.state else {
+        return false;
+    };
+
+    let piece = match helpers::offset_for_player(condition, ctx.current_player()) {
+        Some(offset) => {
+            let Some(abs) = ctx.game.offset(ctx.source_position, offset) else {
+                return false;
+            };
+            let Some(piece) = ctx.game.piece_at_position(&abs) else {
+                return false;
+            };
+            piece
+        }
+        None => ctx.piece.clone(),
+    };
+
+    helpers::counter_in_bounds(condition, piece.counter(name))
+}
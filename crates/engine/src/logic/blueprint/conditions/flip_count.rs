@@ -0,0 +1,22 @@
+use crate::logic::blueprint::move_blueprint::Condition;
+
+use super::{context::ConditionEvalContext, helpers};
+
+/// Counts the enemy pieces a `LINE_FLIP` would convert on landing; passes when the count is
+/// in [min, max].
+pub fn eval(condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
+    let Some(step) = ctx.blueprint.step.get(ctx.current_player()) else {
+        return false;
+    };
+
+    let Some(target) = ctx.game.offset(ctx.source_position, step) else {
+        return false;
+    };
+
+    let count = ctx
+        .game
+        .line_flips(&target, ctx.current_player(), Some(ctx.original_source))
+        .len();
+    let (min, max) = helpers::count_bounds(condition);
+    count >= min as usize && count <= max as usize
+}
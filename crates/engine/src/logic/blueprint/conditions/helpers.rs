@@ -1,6 +1,5 @@
 use crate::logic::blueprint::move_blueprint::Condition;
-use crate::logic::{Game, Piece};
-use crate::shared::{into_position, ExtendedPosition, Position};
+use crate::shared::ExtendedPosition;
 
 pub fn offset_for_player<'a>(
     condition: &'a Condition,
@@ -10,21 +9,20 @@ pub fn offset_for_player<'a>(
     pos_map.get(current_player)
 }
 
-pub fn absolute_position(
-    source_position: &Position,
-    offset: &ExtendedPosition,
-) -> ExtendedPosition {
-    source_position
-        .iter()
-        .zip(offset.iter())
-        .map(|(&src, &delta)| src as i16 + delta)
-        .collect()
+pub fn count_bounds(condition: &Condition) -> (u8, u8) {
+    (condition.min.unwrap_or(0), condition.max.unwrap_or(u8::MAX))
 }
 
-pub fn piece_at_absolute(game: &Game, absolute_position: &ExtendedPosition) -> Option<Piece> {
-    game.piece_at_position(&into_position(absolute_position))
+/// Whether a piece counter or game variable lies in [min, max]. Without `max` there is no upper bound.
+pub fn counter_in_bounds(condition: &Condition, value: u16) -> bool {
+    in_bounds(
+        value.into(),
+        condition.min.unwrap_or(0).into(),
+        condition.max.map(u32::from),
+    )
 }
 
-pub fn count_bounds(condition: &Condition) -> (u8, u8) {
-    (condition.min.unwrap_or(0), condition.max.unwrap_or(u8::MAX))
+/// Whether `value` lies in [min, max]. Without `max` there is no upper bound.
+pub fn in_bounds(value: u32, min: u32, max: Option<u32>) -> bool {
+    value >= min && max.is_none_or(|max| value <= max)
 }
@@ -1,9 +1,12 @@
 mod ally_adjacent_count;
 mod check_state;
 pub mod context;
+mod counter;
 mod depends_on;
 mod first_move;
-mod helpers;
+mod flip_count;
+pub(crate) mod helpers;
+mod named;
 mod not_attacked;
 mod opponent_not_in_check;
 mod path_empty;
@@ -14,11 +17,14 @@ mod piece_first_move;
 mod piece_not_at;
 mod rook_first_move;
 mod source_not_attacked;
+mod variable;
 
+use crate::logic::{Game, Piece};
 use crate::shared::{
-    ALLY_ADJACENT_COUNT, CHECK_STATE, DEPENDS_ON, FIRST_MOVE, NOT_ATTACKED, OPPONENT_NOT_IN_CHECK,
-    PATH_EMPTY, PATH_NOT_ATTACKED, PATH_PIECE_COUNT, PIECE_AT, PIECE_FIRST_MOVE, PIECE_NOT_AT,
-    ROOK_FIRST_MOVE, SOURCE_NOT_ATTACKED,
+    Position, ALLY_ADJACENT_COUNT, ALL_OF, ANY_OF, CHECK_STATE, COUNTER, DEPENDS_ON, FIRST_MOVE,
+    FLIP_COUNT, NOT, NOT_ATTACKED, OPPONENT_NOT_IN_CHECK, PATH_EMPTY, PATH_NOT_ATTACKED,
+    PATH_PIECE_COUNT, PIECE_AT, PIECE_FIRST_MOVE, PIECE_NOT_AT, ROOK_FIRST_MOVE,
+    SOURCE_NOT_ATTACKED, VARIABLE,
 };
 
 use super::move_blueprint::Condition;
@@ -40,6 +46,45 @@ pub fn evaluate_condition(condition: &Condition, ctx: &ConditionEvalContext<'_>)
         PIECE_NOT_AT => piece_not_at::eval(condition, ctx),
         ALLY_ADJACENT_COUNT => ally_adjacent_count::eval(condition, ctx),
         OPPONENT_NOT_IN_CHECK => opponent_not_in_check::eval(condition, ctx),
+        FLIP_COUNT => flip_count::eval(condition, ctx),
+        COUNTER => counter::eval(condition, ctx),
+        VARIABLE => variable::eval(condition, ctx),
+        ALL_OF => condition
+            .conditions
+            .iter()
+            .all(|c| evaluate_condition(c, ctx)),
+        ANY_OF => condition
+            .conditions
+            .iter()
+            .any(|c| evaluate_condition(c, ctx)),
+        NOT => !condition
+            .conditions
+            .iter()
+            .all(|c| evaluate_condition(c, ctx)),
+        code if named::is_square_code(code, ctx) => named::eval(condition, ctx),
         _ => true, // unknown conditions pass silently for forward compatibility
     }
 }
+
+/// Evaluates a condition about a single square, as used by modifiers and `repeat.until`: an
+/// occupancy state or named global condition on `square`, a `COUNTER` on `piece`, or an ALL /
+/// ANY / NOT node over those.
+pub fn evaluate_square_condition(
+    condition: &Condition,
+    square: &Position,
+    piece: &Piece,
+    game: &Game,
+) -> bool {
+    let nested = |c: &Condition| evaluate_square_condition(c, square, piece, game);
+
+    match condition.code.as_str() {
+        COUNTER => condition
+            .state
+            .as_ref()
+            .is_some_and(|name| helpers::counter_in_bounds(condition, piece.counter(name))),
+        ALL_OF => condition.conditions.iter().all(nested),
+        ANY_OF => condition.conditions.iter().any(nested),
+        NOT => !condition.conditions.iter().all(nested),
+        code => game.check_position_condition(square, &code.to_string()),
+    }
+}
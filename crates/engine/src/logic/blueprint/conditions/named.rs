@@ -0,0 +1,21 @@
+use crate::logic::blueprint::move_blueprint::Condition;
+use crate::shared::{ALLY, EMPTY, ENEMY, NOT_EMPTY};
+
+use super::context::ConditionEvalContext;
+
+/// Whether `code` is an occupancy state or a named global condition, checked on a square.
+pub fn is_square_code(code: &str, ctx: &ConditionEvalContext<'_>) -> bool {
+    [EMPTY, NOT_EMPTY, ALLY, ENEMY].contains(&code) || ctx.game.conditions.contains_key(code)
+}
+
+/// Checks an occupancy state or named global condition on the square the move steps onto.
+pub fn eval(condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
+    let Some(step) = ctx.blueprint.step.get(ctx.current_player()) else {
+        return false;
+    };
+    let Some(target) = ctx.game.offset(ctx.source_position, step) else {
+        return false;
+    };
+
+    ctx.game.check_position_condition(&target, &condition.code)
+}
@@ -1,5 +1,4 @@
 use crate::logic::blueprint::move_blueprint::Condition;
-use crate::shared::into_position;
 
 use super::context::ConditionEvalContext;
 
@@ -8,23 +7,15 @@ pub fn eval(_condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
         return false;
     };
 
-    let max_steps = step.iter().map(|&delta| delta.abs()).max().unwrap_or(0);
+    let (unit, max_steps) = ctx.game.board.path_unit(step);
     if max_steps <= 1 {
         return true;
     }
 
-    let unit: Vec<i16> = step.iter().map(|&delta| delta.signum()).collect();
     (1..max_steps).all(|distance| {
-        let position: Vec<i16> = ctx
-            .source_position
-            .iter()
-            .zip(unit.iter())
-            .map(|(&src, &u)| src as i16 + u * distance)
-            .collect();
-        ctx.game.board.is_position_valid(&position)
-            && ctx
-                .game
-                .piece_at_position(&into_position(&position))
-                .is_none()
+        let delta: Vec<i16> = unit.iter().map(|&u| u * distance).collect();
+        ctx.game
+            .offset(ctx.source_position, &delta)
+            .is_some_and(|position| ctx.game.piece_at_position(&position).is_none())
     })
 }
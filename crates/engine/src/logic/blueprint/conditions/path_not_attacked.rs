@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use crate::logic::blueprint::move_blueprint::Condition;
-use crate::shared::{into_position, Position};
+use crate::shared::Position;
 
 use super::context::ConditionEvalContext;
 
@@ -10,12 +10,11 @@ pub fn eval(_condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
         return false;
     };
 
-    let max_steps = step.iter().map(|&delta| delta.abs()).max().unwrap_or(0);
+    let (unit, max_steps) = ctx.game.board.path_unit(step);
     if max_steps <= 0 {
         return true;
     }
 
-    let unit: Vec<i16> = step.iter().map(|&delta| delta.signum()).collect();
     let opponent_attacks: HashSet<Position> = ctx
         .game
         .players
@@ -25,13 +24,9 @@ pub fn eval(_condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
         .collect();
 
     (1..=max_steps).all(|distance| {
-        let position: Vec<i16> = ctx
-            .source_position
-            .iter()
-            .zip(unit.iter())
-            .map(|(&src, &u)| src as i16 + u * distance)
-            .collect();
-        ctx.game.board.is_position_valid(&position)
-            && !opponent_attacks.contains(&into_position(&position))
+        let delta: Vec<i16> = unit.iter().map(|&u| u * distance).collect();
+        ctx.game
+            .offset(ctx.source_position, &delta)
+            .is_some_and(|position| !opponent_attacks.contains(&position))
     })
 }
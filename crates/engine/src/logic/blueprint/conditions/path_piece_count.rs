@@ -1,5 +1,5 @@
 use crate::logic::blueprint::move_blueprint::Condition;
-use crate::shared::into_position;
+use crate::shared::ExtendedPosition;
 
 use super::{context::ConditionEvalContext, helpers};
 
@@ -8,36 +8,24 @@ pub fn eval(condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
         return false;
     };
 
-    let target = helpers::absolute_position(ctx.source_position, step);
-    if !ctx.game.board.is_position_valid(&target) {
+    if ctx.game.offset(ctx.source_position, step).is_none() {
         return false;
     }
 
-    let diff: Vec<i16> = target
-        .iter()
-        .zip(ctx.original_source.iter())
-        .map(|(&target_coord, &source_coord)| target_coord - source_coord as i16)
-        .collect();
-    let max_distance = diff.iter().map(|&delta| delta.abs()).max().unwrap_or(0);
+    let diff = displacement(ctx, step);
+    let (unit, max_distance) = ctx.game.board.path_unit(&diff);
     if max_distance == 0 {
         return false;
     }
 
-    let unit: Vec<i16> = diff.iter().map(|&delta| delta.signum()).collect();
     let mut count = 0u8;
     for distance in 1..max_distance {
-        let position: Vec<i16> = ctx
-            .original_source
-            .iter()
-            .zip(unit.iter())
-            .map(|(&src, &u)| src as i16 + u * distance)
-            .collect();
-
-        if !ctx.game.board.is_position_valid(&position) {
+        let delta: Vec<i16> = unit.iter().map(|&u| u * distance).collect();
+        let Some(position) = ctx.game.offset(ctx.original_source, &delta) else {
             continue;
-        }
+        };
 
-        if let Some(piece) = ctx.game.piece_at_position(&into_position(&position)) {
+        if let Some(piece) = ctx.game.piece_at_position(&position) {
             let code_matches = condition
                 .piece_code
                 .as_ref()
@@ -51,3 +39,30 @@ pub fn eval(condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
     let (min, max) = helpers::count_bounds(condition);
     count >= min && count <= max
 }
+
+/// Displacement from the original source to `source_position + step`. A slide reaches its
+/// current source by repeating `step`, so counting those steps keeps the result right when the
+/// slide wrapped around the board. Otherwise falls back to the coordinate difference.
+fn displacement(ctx: &ConditionEvalContext<'_>, step: &ExtendedPosition) -> ExtendedPosition {
+    let limit = ctx.game.board.dimensions.iter().copied().max().unwrap_or(0) as i16;
+
+    let mut current = ctx.original_source.clone();
+    let mut steps = 1i16;
+    while current != *ctx.source_position && steps <= limit {
+        let Some(next) = ctx.game.offset(&current, step) else {
+            break;
+        };
+        current = next;
+        steps += 1;
+    }
+
+    if current == *ctx.source_position {
+        return step.iter().map(|&delta| delta * steps).collect();
+    }
+    ctx.source_position
+        .iter()
+        .zip(ctx.original_source.iter())
+        .zip(step.iter())
+        .map(|((&source, &original), &delta)| source as i16 - original as i16 + delta)
+        .collect()
+}
@@ -7,10 +7,11 @@ pub fn eval(condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
         return false;
     };
 
-    let abs = helpers::absolute_position(ctx.source_position, offset);
-    if !ctx.game.board.is_position_valid(&abs) {
+    let Some(abs) = ctx.game.offset(ctx.source_position, offset) else {
         return true; // off-board -> pass
-    }
+    };
 
-    helpers::piece_at_absolute(ctx.game, &abs).map_or(true, |piece| piece.total_moves == 0)
+    ctx.game
+        .piece_at_position(&abs)
+        .map_or(true, |piece| piece.total_moves == 0)
 }
@@ -0,0 +1,16 @@
+use crate::logic::blueprint::move_blueprint::Condition;
+
+use super::{context::ConditionEvalContext, helpers};
+
+/// Compares a game variable against [min, max]: the moving player's own, or the shared one with
+/// the "GLOBAL" scope.
+pub fn eval(condition: &Condition, ctx: &ConditionEvalContext<'_>) -> bool {
+    let Some(name) = &condition.variable else {
+        return false;
+    };
+
+    let value = ctx
+        .game
+        .variable(name, condition.scope.as_deref(), ctx.current_player());
+    helpers::counter_in_bounds(condition, value)
+}
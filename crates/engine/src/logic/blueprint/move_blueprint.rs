@@ -4,12 +4,13 @@ use super::{
     conditions::{self, context::ConditionEvalContext},
     side_effects::{self, context::SideEffectContext},
 };
-use crate::logic::{Board, Game, Piece};
+use crate::logic::{Game, Piece};
 use crate::shared::{
-    apply_direction, into_position, BoardChange, Effect, EffectMetadata, ExtendedPosition,
-    Position, ALLY, EMPTY, ENEMY, NOT_EMPTY, OPPONENT_NOT_IN_CHECK,
+    apply_direction, BoardChange, Continuation, Effect, EffectMetadata, ExtendedPosition, Position,
+    VariableChange, ALLY, CAPTURE, CONVERT, CUSTODIAL_CAPTURE, EMPTY, ENEMY, EXPLODE, NEUTRAL,
+    NOT_EMPTY, OPPONENT_NOT_IN_CHECK, SHOOT,
 };
-use crate::specs::{MoveSpec, PlayerSpec};
+use crate::specs::{ConditionSpec, MoveSpec, PlayerSpec, SideEffectSpec};
 
 #[derive(Clone, Debug)]
 pub struct Condition {
@@ -29,16 +30,46 @@ pub struct Condition {
     // For PIECE_AT / PIECE_NOT_AT / ALLY_ADJACENT_COUNT: piece code to check.
     pub piece_code: Option<String>,
 
-    // For PATH_PIECE_COUNT / ALLY_ADJACENT_COUNT: inclusive lower bound (default 0).
+    // For PATH_PIECE_COUNT / ALLY_ADJACENT_COUNT / FLIP_COUNT: inclusive lower bound (default 0).
     pub min: Option<u8>,
 
-    // For PATH_PIECE_COUNT / ALLY_ADJACENT_COUNT: inclusive upper bound (default u8::MAX).
+    // For PATH_PIECE_COUNT / ALLY_ADJACENT_COUNT / FLIP_COUNT: inclusive upper bound
+    // (default u8::MAX).
     pub max: Option<u8>,
+
+    // For VARIABLE: the game variable to read, and "PLAYER" (default) or "GLOBAL".
+    pub variable: Option<String>,
+    pub scope: Option<String>,
+
+    // For ALL / ANY / NOT: the nested conditions.
+    pub conditions: Vec<Condition>,
+}
+
+impl Condition {
+    /// Builds a condition, transforming its relative position (if any) per player.
+    pub fn from_spec(spec: &ConditionSpec, players: &[PlayerSpec]) -> Self {
+        Condition {
+            code: spec.condition.clone(),
+            move_id: spec.move_id,
+            state: spec.state.clone(),
+            position: transform_pos(spec.position.as_deref(), players),
+            piece_code: spec.piece.clone(),
+            min: spec.min,
+            max: spec.max,
+            variable: spec.variable.clone(),
+            scope: spec.scope.clone(),
+            conditions: spec
+                .conditions
+                .iter()
+                .map(|c| Condition::from_spec(c, players))
+                .collect(),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct MoveRepeat {
-    pub until: String,
+    pub until: Condition,
     pub times: u8,
     pub loop_move: bool,
 }
@@ -59,6 +90,7 @@ pub struct SideEffectBlueprint {
     /// "SET_STATE", "CAPTURE", "MOVE" (move-piece), etc.
     pub action: String,
     /// For SET_STATE: the state flag name to set on the moved piece.
+    /// For INCREMENT / DECREMENT / RESET: the counter to change.
     pub state: Option<String>,
     /// For SET_STATE: countdown duration. None = permanent (Blank flag).
     pub duration: Option<u8>,
@@ -72,6 +104,77 @@ pub struct SideEffectBlueprint {
     /// For CONVERT: the piece code to place at the target square (belonging to the acting player).
     /// If absent, defaults to the acting piece's own code.
     pub piece: Option<String>,
+    /// For DISABLE / ENABLE: absolute square to toggle, used when `target` is absent.
+    pub square: Option<Position>,
+    /// For CUSTODIAL_CAPTURE: "ORTHOGONAL" or "ALL". None = orthogonal.
+    pub directions: Option<String>,
+    /// For CUSTODIAL_CAPTURE: POSITION conditions whose squares act as an ally of the mover.
+    pub hostile: Vec<String>,
+    /// For CUSTODIAL_CAPTURE / EXPLODE: piece codes that cannot be captured.
+    pub immune: Vec<String>,
+    /// For CUSTODIAL_CAPTURE: piece codes that must be surrounded on every side.
+    pub enclosed: Vec<String>,
+    /// For EXPLODE: reach of the blast in steps. None = 1.
+    pub radius: Option<u8>,
+    /// For INCREMENT / DECREMENT: step size. For SET_VARIABLE / INCREMENT_VARIABLE: the value
+    /// or step size. None = 1.
+    pub amount: Option<u16>,
+    /// For INCREMENT / DECREMENT / RESET: "CAPTURED", or None for the moving piece.
+    pub subject: Option<String>,
+    /// For the variable side effects: the game variable to change.
+    pub variable: Option<String>,
+    /// For the variable side effects: "PLAYER" or "GLOBAL". None = the acting player's.
+    pub scope: Option<String>,
+    /// Gate for the side effect, which only fires when it holds. None = always fires. Drops
+    /// check it like a drop restriction.
+    pub condition: Option<Condition>,
+    /// Piece codes that may stand in for the gate's `piece`: it holds when it holds for any.
+    pub options: Vec<String>,
+}
+
+impl SideEffectBlueprint {
+    pub fn from_spec(spec: &SideEffectSpec, players_spec: &[PlayerSpec]) -> Self {
+        SideEffectBlueprint {
+            action: spec.action.clone(),
+            state: spec.state.clone(),
+            duration: spec.duration,
+            from: transform_pos(spec.from.as_deref(), players_spec),
+            to: transform_pos(spec.to.as_deref(), players_spec),
+            target: transform_pos(spec.target.as_deref(), players_spec),
+            piece: spec.piece.clone(),
+            square: spec.square.clone(),
+            directions: spec.directions.clone(),
+            hostile: spec.hostile.clone(),
+            immune: spec.immune.clone(),
+            enclosed: spec.enclosed.clone(),
+            radius: spec.radius,
+            amount: spec.amount,
+            subject: spec.subject.clone(),
+            variable: spec.variable.clone(),
+            scope: spec.scope.clone(),
+            condition: spec
+                .condition
+                .as_ref()
+                .map(|c| Condition::from_spec(c, players_spec)),
+            options: spec.options.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Transforms a raw relative position for all players.
+fn transform_pos(
+    raw: Option<&[i8]>,
+    players: &[PlayerSpec],
+) -> Option<HashMap<String, ExtendedPosition>> {
+    raw.map(|r| {
+        players
+            .iter()
+            .map(|p| {
+                let vec: ExtendedPosition = r.iter().map(|&c| c as i16).collect();
+                (p.name.clone(), apply_direction(&p.direction, &vec))
+            })
+            .collect()
+    })
 }
 
 /// Runtime representation of a move action, bundling the action string with
@@ -82,6 +185,9 @@ pub struct ActionBlueprint {
     pub action: String,
     pub conditions: Vec<Condition>,
     pub side_effects: Vec<SideEffectBlueprint>,
+
+    // Moves the piece may continue with after this action, in the same turn.
+    pub continuation: Option<Continuation>,
 }
 
 /// A `MoveBlueprint` is a factory for a single move. The move could be repeatable (i.e. Rooks),
@@ -107,54 +213,18 @@ pub struct MoveBlueprint {
     // Number of times to repeat the move. `0u8` means repeat indefinitely.
     // Defaults to `1u8`.
     pub repeat_options: MoveRepeat,
+
+    // Whether this move contributes to the attack map. See `MoveSpec::attacks`.
+    pub attacks: bool,
 }
 
 impl MoveBlueprint {
     pub fn from_spec(spec: MoveSpec, players_spec: Vec<PlayerSpec>) -> Self {
-        // Helper: transform a raw [i8; 2] relative position for all players.
-        fn transform_pos(
-            raw: Option<[i8; 2]>,
-            players: &[PlayerSpec],
-        ) -> Option<HashMap<String, ExtendedPosition>> {
-            raw.map(|r| {
-                players
-                    .iter()
-                    .map(|p| {
-                        let vec: ExtendedPosition = vec![r[0] as i16, r[1] as i16];
-                        (p.name.clone(), apply_direction(&p.direction, &vec))
-                    })
-                    .collect()
-            })
-        }
-
-        // Helper: convert a ConditionSpec slice into Condition structs, transforming
-        // any relative positions per player.
-        fn build_conditions(
-            specs: &[crate::specs::game::piece::condition::ConditionSpec],
-            players: &[PlayerSpec],
-        ) -> Vec<Condition> {
+        // Helper: convert a ConditionSpec slice into Condition structs.
+        fn build_conditions(specs: &[ConditionSpec], players: &[PlayerSpec]) -> Vec<Condition> {
             specs
                 .iter()
-                .map(|c| {
-                    let position = c.position.map(|rel| {
-                        players
-                            .iter()
-                            .map(|p| {
-                                let canon = vec![rel[0] as i16, rel[1] as i16];
-                                (p.name.clone(), apply_direction(&p.direction, &canon))
-                            })
-                            .collect()
-                    });
-                    Condition {
-                        code: c.condition.clone(),
-                        move_id: c.move_id,
-                        state: c.state.clone(),
-                        position,
-                        piece_code: c.piece.clone(),
-                        min: c.min,
-                        max: c.max,
-                    }
-                })
+                .map(|c| Condition::from_spec(c, players))
                 .collect()
         }
 
@@ -165,15 +235,7 @@ impl MoveBlueprint {
             let action_side_effects: Vec<SideEffectBlueprint> = action_spec
                 .side_effects
                 .iter()
-                .map(|se| SideEffectBlueprint {
-                    action: se.action.clone(),
-                    state: se.state.clone(),
-                    duration: se.duration,
-                    from: transform_pos(se.from, &players_spec),
-                    to: transform_pos(se.to, &players_spec),
-                    target: transform_pos(se.target, &players_spec),
-                    piece: se.piece.clone(),
-                })
+                .map(|se| SideEffectBlueprint::from_spec(se, &players_spec))
                 .collect();
             actions.insert(
                 action_spec.state,
@@ -181,6 +243,10 @@ impl MoveBlueprint {
                     action: action_spec.action,
                     conditions: action_conditions,
                     side_effects: action_side_effects,
+                    continuation: action_spec.continuation.map(|c| Continuation {
+                        move_ids: c.moves,
+                        mandatory: c.mandatory,
+                    }),
                 },
             );
         }
@@ -196,12 +262,31 @@ impl MoveBlueprint {
         // Process repeat information.
         let (until, times, loop_move) = match spec.repeat {
             Some(repeat) => (
-                repeat.until.unwrap_or_else(|| NOT_EMPTY.to_string()),
+                repeat
+                    .until
+                    .unwrap_or_else(|| ConditionSpec::named(NOT_EMPTY)),
                 repeat.times.unwrap_or(1u8),
                 repeat.loop_move,
             ),
-            None => (NOT_EMPTY.to_string(), 1u8, false),
+            None => (ConditionSpec::named(NOT_EMPTY), 1u8, false),
         };
+        let until = Condition::from_spec(&until, &players_spec);
+
+        // Moves attack by default when they can take an enemy piece, either directly or
+        // through a side effect that removes or takes over pieces.
+        let attacks = spec.attacks.unwrap_or_else(|| {
+            actions.contains_key(ENEMY)
+                || spec
+                    .side_effects
+                    .iter()
+                    .map(|se| se.action.as_str())
+                    .chain(
+                        actions
+                            .values()
+                            .flat_map(|a| a.side_effects.iter().map(|se| se.action.as_str())),
+                    )
+                    .any(|action| [CAPTURE, CONVERT, CUSTODIAL_CAPTURE, EXPLODE].contains(&action))
+        });
 
         // Process move-level conditions.
         let conditions = build_conditions(&spec.conditions, &players_spec);
@@ -212,19 +297,7 @@ impl MoveBlueprint {
             .iter()
             .map(|m| Modifier {
                 action: m.action.clone(),
-                conditions: m
-                    .conditions
-                    .iter()
-                    .map(|c| Condition {
-                        code: c.condition.clone(),
-                        move_id: None, // modifier conditions never use DEPENDS_ON
-                        state: c.state.clone(),
-                        position: None,
-                        piece_code: c.piece.clone(),
-                        min: c.min,
-                        max: c.max,
-                    })
-                    .collect(),
+                conditions: build_conditions(&m.conditions, &players_spec),
                 options: m.options.clone(),
             })
             .collect();
@@ -233,15 +306,7 @@ impl MoveBlueprint {
         let side_effects: Vec<SideEffectBlueprint> = spec
             .side_effects
             .iter()
-            .map(|se| SideEffectBlueprint {
-                action: se.action.clone(),
-                state: se.state.clone(),
-                duration: se.duration,
-                from: transform_pos(se.from, &players_spec),
-                to: transform_pos(se.to, &players_spec),
-                target: transform_pos(se.target, &players_spec),
-                piece: se.piece.clone(),
-            })
+            .map(|se| SideEffectBlueprint::from_spec(se, &players_spec))
             .collect();
 
         MoveBlueprint {
@@ -256,6 +321,7 @@ impl MoveBlueprint {
                 times,
                 loop_move,
             },
+            attacks,
         }
     }
 
@@ -290,11 +356,22 @@ impl MoveBlueprint {
             let max_iterations_reached =
                 !self.repeat_options.loop_move && iterations >= self.repeat_options.times;
             let until_condition_met = match &next_position {
-                Some(pos) => game.check_position_condition(pos, &self.repeat_options.until),
+                Some(pos) => conditions::evaluate_square_condition(
+                    &self.repeat_options.until,
+                    pos,
+                    piece,
+                    game,
+                ),
                 None => false,
             };
-
-            if invalid_next_position || max_iterations_reached || until_condition_met {
+            // On wrapping boards a slide can come back around to where it started.
+            let back_at_source = next_position.as_ref() == Some(&original_source);
+
+            if invalid_next_position
+                || max_iterations_reached
+                || until_condition_met
+                || back_at_source
+            {
                 break;
             }
 
@@ -322,26 +399,20 @@ impl MoveBlueprint {
 
         let mut result_moves: Vec<(Position, Effect)> = Vec::new();
 
-        // Component-wise addition of step (already transformed for this player).
-        let target_position: Vec<i16> = source_position
-            .iter()
-            .zip(self.step.get(&current_player).unwrap().iter())
-            .map(|(&a, &b)| a as i16 + b)
-            .collect();
-
-        if !game.board.is_position_valid(&target_position) {
+        // Component-wise addition of step (already transformed for this player), wrapping
+        // around the board's edges where it wraps.
+        let Some(target_position) =
+            game.offset(source_position, self.step.get(&current_player).unwrap())
+        else {
             return (None, None);
-        }
-
-        let target_position = into_position(&target_position);
+        };
         let target_position_piece = game.piece_at_position(&target_position);
 
-        let state = if target_position_piece.is_none() {
-            EMPTY
-        } else if target_position_piece.unwrap().player == current_player {
-            ALLY
-        } else {
-            ENEMY
+        let state = match &target_position_piece {
+            None => EMPTY,
+            Some(p) if p.is_neutral() => NEUTRAL,
+            Some(p) if p.player == current_player => ALLY,
+            Some(_) => ENEMY,
         };
 
         // Check move-level conditions.
@@ -383,13 +454,28 @@ impl MoveBlueprint {
                     piece,
                     original_source,
                     source_position,
+                    &target_position,
+                    &action_bp.action,
                 );
 
-                for se in self
+                // Side effects with a condition only fire when it holds.
+                let firing: Vec<&SideEffectBlueprint> = self
                     .side_effects
                     .iter()
                     .chain(action_bp.side_effects.iter())
-                {
+                    .filter(|se| {
+                        self.side_effect_fires(
+                            se,
+                            piece,
+                            original_source,
+                            source_position,
+                            valid_move_ids,
+                            game,
+                        )
+                    })
+                    .collect();
+
+                for se in &firing {
                     side_effects::apply_side_effect(
                         se,
                         &side_effect_context,
@@ -397,24 +483,47 @@ impl MoveBlueprint {
                         &mut extra_changes,
                     );
                 }
+                let variable_changes: Vec<VariableChange> = firing
+                    .iter()
+                    .filter_map(|se| side_effects::variable_change(se, &side_effect_context))
+                    .collect();
+
+                // A shooting piece stays put and only the target square is cleared.
+                let shoots = action_bp.action == SHOOT;
 
-                // Check for a modifier (e.g. pawn promotion).
+                // Check for a modifier (e.g. pawn promotion). A piece that never lands, or is
+                // destroyed by its own side effects (e.g. EXPLODE), has nothing to transform.
+                // COUNTER conditions read the moved piece, so this move's side effects count.
+                // Every other condition is checked on the target square.
+                let destroyed = extra_changes
+                    .iter()
+                    .any(|change| change.position == target_position && change.piece.is_none());
                 let mut applied_modifier: Option<Modifier> = None;
-                for modifier in &self.modifiers {
-                    if modifier
-                        .conditions
-                        .iter()
-                        .all(|c| game.check_position_condition(&target_position, &c.code))
-                    {
+                for modifier in self.modifiers.iter().filter(|_| !shoots && !destroyed) {
+                    if modifier.conditions.iter().all(|c| {
+                        conditions::evaluate_square_condition(
+                            c,
+                            &target_position,
+                            &moved_piece,
+                            game,
+                        )
+                    }) {
                         applied_modifier = Some(modifier.clone());
                         break;
                     }
                 }
 
-                let mut board_changes = vec![
-                    BoardChange::clear(source_position),
-                    BoardChange::set_piece(target_position.clone(), moved_piece),
-                ];
+                let mut board_changes = if shoots {
+                    vec![
+                        BoardChange::set_piece(original_source.clone(), moved_piece),
+                        BoardChange::clear(&target_position),
+                    ]
+                } else {
+                    vec![
+                        BoardChange::clear(original_source),
+                        BoardChange::set_piece(target_position.clone(), moved_piece),
+                    ]
+                };
                 board_changes.extend(extra_changes);
 
                 match applied_modifier {
@@ -425,6 +534,8 @@ impl MoveBlueprint {
                                 action: modifier.action,
                                 board_changes,
                                 metadata: Some(EffectMetadata::Options(modifier.options)),
+                                continuation: None,
+                                variable_changes,
                             },
                         ));
                     }
@@ -435,6 +546,8 @@ impl MoveBlueprint {
                                 action: action_bp.action.clone(),
                                 board_changes,
                                 metadata: None,
+                                continuation: action_bp.continuation.clone(),
+                                variable_changes,
                             },
                         ));
                     }
@@ -451,39 +564,27 @@ impl MoveBlueprint {
             .any(|c| c.code == OPPONENT_NOT_IN_CHECK)
         {
             result_moves.retain(|(_, effect)| {
-                let mut sim = game.state.pieces.clone();
-                for change in &effect.board_changes {
-                    match &change.piece {
-                        Some(p) => {
-                            sim.insert(change.position.clone(), p.clone());
-                        }
-                        None => {
-                            sim.remove(&change.position);
-                        }
-                    }
-                }
+                let (sim, disabled_positions) = game.simulate_effect(effect);
                 game.players
                     .iter()
                     .filter(|p| **p != current_player.as_str())
                     .all(|opp| {
-                        if game.leader.is_empty() {
+                        let leaders = game.leaders(opp);
+                        if leaders.is_empty() {
                             return true;
                         }
                         let leader_pos: Vec<Position> = sim
                             .iter()
-                            .filter(|(_, p)| {
-                                p.player == opp.as_str() && game.leader.contains(&p.code)
-                            })
+                            .filter(|(_, p)| p.player == opp.as_str() && leaders.contains(&p.code))
                             .map(|(pos, _)| pos.clone())
                             .collect();
                         if leader_pos.is_empty() {
                             return true;
                         }
-                        let my_attacks = crate::logic::Game::attacked_by_pieces(
+                        let my_attacks = game.attacked_by_pieces(
                             current_player.as_str(),
                             &sim,
-                            &game.board,
-                            &game.blueprints,
+                            &disabled_positions,
                         );
                         leader_pos.iter().all(|lp| !my_attacks.contains(lp))
                     })
@@ -493,112 +594,110 @@ impl MoveBlueprint {
         (Some(result_moves), Some(target_position))
     }
 
-    /// Returns the set of squares threatened by this move blueprint from `source_position`,
-    /// ignoring move-level conditions (threat is about board geometry, not turn state).
-    /// Only blueprints with an `ENEMY→CAPTURE` action contribute to the attack map.
+    /// Returns the squares this move attacks when `piece` stands on `source_position`.
+    ///
+    /// `game` must have the attacking player to move (see `Game::threat_view`). `moves` are
+    /// the moves this blueprint produced there, so threats follow the actual board changes:
+    /// any square whose enemy piece a move would remove or take over is attacked. This covers
+    /// en passant, conversion and captures that land elsewhere. Empty squares are attacked
+    /// when the `ENEMY` action would fire if an enemy stood there.
     pub fn calculate_threats(
         &self,
-        player: &str,
+        piece: &Piece,
         source_position: &Position,
+        valid_move_ids: &HashSet<u8>,
+        moves: Option<&[(Position, Effect)]>,
         game: &Game,
     ) -> HashSet<Position> {
-        if !self.actions.contains_key(ENEMY) {
+        if !self.attacks {
             return HashSet::new();
         }
 
-        let mut threats = HashSet::new();
-        let mut iterations: u8 = 1;
-        let mut current_source = source_position.clone();
-
-        loop {
-            let Some(step) = self.step.get(player) else {
-                break;
-            };
-            let target: Vec<i16> = current_source
-                .iter()
-                .zip(step.iter())
-                .map(|(&s, &st)| s as i16 + st)
-                .collect();
-
-            if !game.board.is_position_valid(&target) {
-                break;
-            }
-            let target_pos = into_position(&target);
-
-            match game.piece_at_position(&target_pos) {
-                Some(p) if p.player == player => break, // ally blocks
-                Some(_) => {
-                    threats.insert(target_pos);
-                    break;
-                } // enemy: threatened, stop
-                None => {
-                    threats.insert(target_pos.clone());
-                } // empty: threatened, continue
-            }
-
-            let max_iterations_reached =
-                !self.repeat_options.loop_move && iterations >= self.repeat_options.times;
-            if max_iterations_reached {
-                break;
-            }
-
-            current_source = target_pos;
-            iterations += 1;
-        }
+        let player = game.current_player();
+        let mut threats: HashSet<Position> = moves
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|(_, effect)| effect.board_changes.iter())
+            .filter(|change| {
+                game.state.pieces.get(&change.position).is_some_and(|existing| {
+                    existing.player != player
+                        && change
+                            .piece
+                            .as_ref()
+                            .is_none_or(|p| p.player != existing.player)
+                })
+            })
+            .map(|change| change.position.clone())
+            .collect();
 
-        threats
-    }
+        let Some(enemy_action) = self.actions.get(ENEMY) else {
+            return threats;
+        };
 
-    /// Like `calculate_threats`, but uses an explicit `pieces` map and `board` instead of
-    /// `game.state.pieces` / `game.board`. Used for simulated check detection.
-    pub fn calculate_threats_with(
-        &self,
-        player: &str,
-        source_position: &Position,
-        pieces: &HashMap<Position, Piece>,
-        board: &Board,
-    ) -> HashSet<Position> {
-        if !self.actions.contains_key(ENEMY) {
-            return HashSet::new();
+        // When EMPTY and ENEMY actions fire under the same conditions, the empty squares the
+        // move reached are exactly the ones an enemy piece could be taken on.
+        if enemy_action.conditions.is_empty()
+            && self
+                .actions
+                .get(EMPTY)
+                .is_some_and(|a| a.conditions.is_empty())
+        {
+            threats.extend(
+                moves
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|(target, _)| game.piece_at_position(target).is_none())
+                    .map(|(target, _)| target.clone()),
+            );
+            return threats;
         }
 
-        let mut threats = HashSet::new();
+        // Otherwise walk the same squares as `calculate_moves`, probing the empty ones.
         let mut iterations: u8 = 1;
         let mut current_source = source_position.clone();
-
         loop {
-            let Some(step) = self.step.get(player) else {
+            let Some(step) = self.step.get(&player) else {
                 break;
             };
-            let target: Vec<i16> = current_source
-                .iter()
-                .zip(step.iter())
-                .map(|(&s, &st)| s as i16 + st)
-                .collect();
-
-            if !board.is_position_valid(&target) {
+            let Some(target) = game.offset(&current_source, step) else {
                 break;
-            }
-            let target_pos = into_position(&target);
+            };
 
-            match pieces.get(&target_pos) {
-                Some(p) if p.player == player => break,
-                Some(_) => {
-                    threats.insert(target_pos);
-                    break;
-                }
-                None => {
-                    threats.insert(target_pos.clone());
-                }
+            if game.piece_at_position(&target).is_none()
+                && self.check_conditions(
+                    piece,
+                    source_position,
+                    &current_source,
+                    valid_move_ids,
+                    game,
+                )
+                && self.evaluate_conditions(
+                    &enemy_action.conditions,
+                    piece,
+                    source_position,
+                    &current_source,
+                    valid_move_ids,
+                    game,
+                )
+            {
+                threats.insert(target.clone());
             }
 
             let max_iterations_reached =
                 !self.repeat_options.loop_move && iterations >= self.repeat_options.times;
-            if max_iterations_reached {
+            if max_iterations_reached
+                || conditions::evaluate_square_condition(
+                    &self.repeat_options.until,
+                    &target,
+                    piece,
+                    game,
+                )
+                || target == *source_position
+            {
                 break;
             }
 
-            current_source = target_pos;
+            current_source = target;
             iterations += 1;
         }
 
@@ -628,6 +727,40 @@ impl MoveBlueprint {
         )
     }
 
+    /// Whether `side_effect` fires: it has no condition, or the condition holds for its own
+    /// `piece` or for any of the side effect's `options`.
+    fn side_effect_fires(
+        &self,
+        side_effect: &SideEffectBlueprint,
+        piece: &Piece,
+        original_source: &Position,
+        source_position: &Position,
+        valid_move_ids: &HashSet<u8>,
+        game: &Game,
+    ) -> bool {
+        let Some(condition) = &side_effect.condition else {
+            return true;
+        };
+        let holds = |c: &Condition| {
+            self.evaluate_conditions(
+                std::slice::from_ref(c),
+                piece,
+                original_source,
+                source_position,
+                valid_move_ids,
+                game,
+            )
+        };
+
+        holds(condition)
+            || side_effect.options.iter().any(|code| {
+                holds(&Condition {
+                    piece_code: Some(code.clone()),
+                    ..condition.clone()
+                })
+            })
+    }
+
     /// Core condition evaluator. Accepts any slice of conditions so it can be used for
     /// both move-level (`self.conditions`) and action-level (`action_bp.conditions`) checks.
     ///
@@ -1,10 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::logic::{Board, Game, Piece};
-use crate::shared::{Effect, Position};
-use crate::specs::{ConditionSpec, PieceSpec, PlayerSpec};
+use crate::logic::{Game, Piece};
+use crate::shared::{BoardChange, Effect, Position, DROP};
+use crate::specs::{PieceSpec, PlayerSpec};
 
-use super::move_blueprint::MoveBlueprint;
+use super::move_blueprint::{Condition, MoveBlueprint, SideEffectBlueprint};
+use super::side_effects::{self, context::SideEffectContext};
 
 /// A `PieceBlueprint` is essentially a factory for piece movement calculation.
 /// It stores the set of rules used to calculate the available moves, but without knowledge
@@ -15,55 +16,150 @@ use super::move_blueprint::MoveBlueprint;
 #[derive(Clone, Debug)]
 pub struct PieceBlueprint {
     pub move_blueprints: Vec<MoveBlueprint>,
-    /// Raw drop-restriction conditions (no direction transform needed).
-    pub drop_restrictions: Vec<ConditionSpec>,
+    /// Drop-restriction conditions, checked at the candidate drop square.
+    pub drop_restrictions: Vec<Condition>,
+    /// Side effects applied to every drop of this piece.
+    pub drop_side_effects: Vec<SideEffectBlueprint>,
 }
 
 impl PieceBlueprint {
     pub fn from_spec(spec: PieceSpec, players_spec: Vec<PlayerSpec>) -> Self {
+        let drop_side_effects = spec
+            .drop_side_effects
+            .iter()
+            .map(|se| SideEffectBlueprint::from_spec(se, &players_spec))
+            .collect();
+
         PieceBlueprint {
             move_blueprints: spec
                 .moves
                 .into_iter()
                 .map(|x| MoveBlueprint::from_spec(x, players_spec.clone()))
                 .collect(),
-            drop_restrictions: spec.drop_restrictions,
+            drop_restrictions: spec
+                .drop_restrictions
+                .iter()
+                .map(|c| Condition::from_spec(c, &players_spec))
+                .collect(),
+            drop_side_effects,
         }
     }
 
-    /// Returns all squares threatened by this piece from `position`.
+    /// Returns all squares this piece attacks from `position`. `game` must have the piece's
+    /// owner to move; see `Game::threat_view`.
     pub fn calculate_threats(
         &self,
-        player: &str,
+        piece: &Piece,
         position: &Position,
         game: &Game,
     ) -> HashSet<Position> {
-        self.move_blueprints
-            .iter()
-            .flat_map(|mb| mb.calculate_threats(player, position, game))
-            .collect()
+        let mut threats = HashSet::new();
+        let mut valid_move_ids: HashSet<u8> = HashSet::new();
+
+        for move_blueprint in &self.move_blueprints {
+            // Non-attacking moves only matter when another move depends on them.
+            let needed = move_blueprint.attacks
+                || self.move_blueprints.iter().any(|other| {
+                    other
+                        .conditions
+                        .iter()
+                        .any(|c| c.move_id == Some(move_blueprint.id))
+                });
+            if !needed {
+                continue;
+            }
+
+            let moves = move_blueprint.calculate_moves(piece, position, &valid_move_ids, game);
+            threats.extend(move_blueprint.calculate_threats(
+                piece,
+                position,
+                &valid_move_ids,
+                moves.as_deref(),
+                game,
+            ));
+            if moves.is_some() {
+                valid_move_ids.insert(move_blueprint.id);
+            }
+        }
+
+        threats
     }
 
-    /// Like `calculate_threats`, but uses an explicit `pieces` map and `board` for simulation.
-    pub fn calculate_threats_with(
+    /// Calculates the moves associated with each move blueprint.
+    pub fn calculate_moves(
         &self,
-        player: &str,
+        piece: &Piece,
         position: &Position,
-        pieces: &HashMap<Position, Piece>,
-        board: &Board,
-    ) -> HashSet<Position> {
-        self.move_blueprints
+        game: &Game,
+    ) -> Option<HashMap<Position, Effect>> {
+        self.collect_moves(piece, position, game, None)
+    }
+
+    /// Calculates only the moves whose id is in `move_ids`, as when continuing a multi-leg move.
+    /// Other moves are still evaluated so that `DEPENDS_ON` conditions resolve as usual.
+    pub fn calculate_continuation_moves(
+        &self,
+        piece: &Piece,
+        position: &Position,
+        game: &Game,
+        move_ids: &[u8],
+    ) -> Option<HashMap<Position, Effect>> {
+        self.collect_moves(piece, position, game, Some(move_ids))
+    }
+
+    /// Builds the effect of dropping `piece` on `position`, including the drop side effects.
+    pub fn drop_effect(&self, piece: &Piece, position: &Position, game: &Game) -> Effect {
+        let mut dropped = piece.clone();
+        let mut extra_changes: Vec<BoardChange> = Vec::new();
+
+        // A drop has no source: side effects are relative to the drop square.
+        let side_effect_context = SideEffectContext::new(
+            game,
+            &piece.player,
+            piece,
+            position,
+            position,
+            position,
+            DROP,
+        );
+        // Side effects with a condition only fire when it holds.
+        let firing: Vec<&SideEffectBlueprint> = self
+            .drop_side_effects
+            .iter()
+            .filter(|se| drop_side_effect_fires(se, position, &piece.player, game))
+            .collect();
+        for se in &firing {
+            side_effects::apply_side_effect(
+                se,
+                &side_effect_context,
+                &mut dropped,
+                &mut extra_changes,
+            );
+        }
+
+        let variable_changes = firing
             .iter()
-            .flat_map(|mb| mb.calculate_threats_with(player, position, pieces, board))
-            .collect()
+            .filter_map(|se| side_effects::variable_change(se, &side_effect_context))
+            .collect();
+
+        let mut board_changes = vec![BoardChange::set_piece(position.clone(), dropped)];
+        board_changes.extend(extra_changes);
+
+        Effect {
+            action: DROP.to_string(),
+            board_changes,
+            metadata: None,
+            continuation: None,
+            variable_changes,
+        }
     }
 
-    /// Calculates the moves associated with each move blueprint.
-    pub fn calculate_moves(
+    fn collect_moves(
         &self,
         piece: &Piece,
         position: &Position,
         game: &Game,
+        allowed_ids: Option<&[u8]>,
     ) -> Option<HashMap<Position, Effect>> {
         let mut moves: HashMap<Position, Effect> = HashMap::new();
 
@@ -76,9 +172,12 @@ impl PieceBlueprint {
                 Some(value) => {
                     // `value` is a vector of (Position, Vec<Effect>), where the position is the "target" position
                     // and the vector is the list of effects to be executed.
-                    value.iter().for_each(|(pos, effects)| {
-                        moves.insert(pos.clone(), effects.clone());
-                    });
+                    let allowed = allowed_ids.is_none_or(|ids| ids.contains(&move_blueprint.id));
+                    if allowed {
+                        value.iter().for_each(|(pos, effects)| {
+                            moves.insert(pos.clone(), effects.clone());
+                        });
+                    }
 
                     valid_move_ids.insert(move_blueprint.id);
                 }
@@ -89,3 +188,25 @@ impl PieceBlueprint {
         (!moves.is_empty()).then_some(moves)
     }
 }
+
+/// Whether a drop side effect fires. Its condition is checked at the drop square like a drop
+/// restriction, with any of the side effect's `options` standing in for the condition's `piece`.
+fn drop_side_effect_fires(
+    side_effect: &SideEffectBlueprint,
+    position: &Position,
+    player: &str,
+    game: &Game,
+) -> bool {
+    let Some(condition) = &side_effect.condition else {
+        return true;
+    };
+
+    game.check_drop_restriction(position, condition, player)
+        || side_effect.options.iter().any(|code| {
+            let alternative = Condition {
+                piece_code: Some(code.clone()),
+                ..condition.clone()
+            };
+            game.check_drop_restriction(position, &alternative, player)
+        })
+}
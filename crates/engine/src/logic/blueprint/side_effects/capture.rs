@@ -19,5 +19,14 @@ pub fn apply(
         return;
     };
 
+    // Neutral pieces cannot be captured.
+    if ctx
+        .game
+        .piece_at_position(&capture_position)
+        .is_some_and(|piece| piece.is_neutral())
+    {
+        return;
+    }
+
     extra_changes.push(BoardChange::clear(&capture_position));
 }
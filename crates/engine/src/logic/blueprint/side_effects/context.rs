@@ -7,6 +7,9 @@ pub struct SideEffectContext<'a> {
     pub acting_piece: &'a Piece,
     pub original_source: &'a Position,
     pub source_position: &'a Position,
+    pub target_position: &'a Position,
+    /// The action being played, e.g. `MOVE`, `CAPTURE`, `SHOOT` or `DROP`.
+    pub action: &'a str,
 }
 
 impl<'a> SideEffectContext<'a> {
@@ -16,6 +19,8 @@ impl<'a> SideEffectContext<'a> {
         acting_piece: &'a Piece,
         original_source: &'a Position,
         source_position: &'a Position,
+        target_position: &'a Position,
+        action: &'a str,
     ) -> Self {
         Self {
             game,
@@ -23,6 +28,8 @@ impl<'a> SideEffectContext<'a> {
             acting_piece,
             original_source,
             source_position,
+            target_position,
+            action,
         }
     }
 }
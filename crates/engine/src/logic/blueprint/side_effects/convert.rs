@@ -22,7 +22,7 @@ pub fn apply(
     let Some(existing) = ctx.game.piece_at_position(&convert_position) else {
         return;
     };
-    if existing.player == ctx.current_player {
+    if existing.player == ctx.current_player || existing.is_neutral() {
         return;
     }
 
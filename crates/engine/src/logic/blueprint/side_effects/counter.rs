@@ -0,0 +1,63 @@
+use crate::logic::blueprint::move_blueprint::SideEffectBlueprint;
+use crate::logic::Piece;
+use crate::shared::{BoardChange, CAPTURED, DECREMENT, INCREMENT, SHOOT};
+
+use super::{context::SideEffectContext, helpers};
+
+/// Changes the named counter in `state` on the moving piece, on the piece at the relative
+/// `target`, or on the piece on the landing square (`"subject": "CAPTURED"`). A captured piece
+/// is only put back with its new count when the acting piece does not land on it (`SHOOT`).
+pub fn apply(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+    moved_piece: &mut Piece,
+    extra_changes: &mut Vec<BoardChange>,
+) {
+    let Some(name) = &side_effect.state else {
+        return;
+    };
+
+    let square = if side_effect.subject.as_deref() == Some(CAPTURED) {
+        if ctx.action != SHOOT {
+            return;
+        }
+        ctx.target_position.clone()
+    } else if side_effect.target.is_some() {
+        let Some(square) = helpers::relative_position(
+            side_effect.target.as_ref(),
+            ctx.source_position,
+            ctx.current_player,
+            ctx.game,
+        ) else {
+            return;
+        };
+        square
+    } else {
+        adjust(side_effect, name, moved_piece);
+        return;
+    };
+
+    if square == *ctx.original_source {
+        adjust(side_effect, name, moved_piece);
+        return;
+    }
+    let Some(mut piece) = ctx.game.piece_at_position(&square) else {
+        return;
+    };
+    if piece.is_neutral() {
+        return;
+    }
+    adjust(side_effect, name, &mut piece);
+    extra_changes.push(BoardChange::set_piece(square, piece));
+}
+
+fn adjust(side_effect: &SideEffectBlueprint, name: &str, piece: &mut Piece) {
+    let amount = side_effect.amount.unwrap_or(1);
+    let current = piece.counter(name);
+    let value = match side_effect.action.as_str() {
+        INCREMENT => current.saturating_add(amount),
+        DECREMENT => current.saturating_sub(amount),
+        _ => 0,
+    };
+    piece.set_counter(name, value);
+}
@@ -0,0 +1,93 @@
+use crate::logic::blueprint::move_blueprint::SideEffectBlueprint;
+use crate::logic::Piece;
+use crate::shared::{BoardChange, ExtendedPosition, Position, ALL_DIRECTIONS, ORTHOGONAL};
+
+use super::context::SideEffectContext;
+
+/// Removes every enemy next to the landing square that is trapped between the moving piece and
+/// an ally or hostile square on the far side. `enclosed` pieces must instead be surrounded on
+/// every side, and `immune` pieces are never removed.
+pub fn apply(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+    _moved_piece: &mut Piece,
+    extra_changes: &mut Vec<BoardChange>,
+) {
+    let board = &ctx.game.board;
+    let directions = directions(side_effect, ctx);
+
+    for offset in &directions {
+        let Some(flanked) = board.shift(ctx.target_position, offset) else {
+            continue;
+        };
+        let Some(piece) = ctx.game.piece_at_position(&flanked) else {
+            continue;
+        };
+        if piece.player == ctx.current_player
+            || piece.is_neutral()
+            || side_effect.immune.contains(&piece.code)
+        {
+            continue;
+        }
+
+        let captured = if side_effect.enclosed.contains(&piece.code) {
+            directions.iter().all(|around| {
+                board
+                    .shift(&flanked, around)
+                    .is_some_and(|square| is_anvil(side_effect, ctx, &square, &piece))
+            })
+        } else {
+            board
+                .shift(&flanked, offset)
+                .is_some_and(|square| is_anvil(side_effect, ctx, &square, &piece))
+        };
+
+        if captured {
+            extra_changes.push(BoardChange::clear(&flanked));
+        }
+    }
+}
+
+/// Steps to the neighbouring cells along which pieces are flanked.
+fn directions(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+) -> Vec<ExtendedPosition> {
+    let mut offsets = ctx.game.board.adjacent_offsets();
+    match side_effect.directions.as_deref().unwrap_or(ORTHOGONAL) {
+        ORTHOGONAL if !ctx.game.board.is_hex() => {
+            offsets.retain(|offset| offset.iter().filter(|&&delta| delta != 0).count() == 1)
+        }
+        ORTHOGONAL | ALL_DIRECTIONS => (),
+        // Rejected by spec validation.
+        _ => offsets.clear(),
+    }
+    offsets
+}
+
+/// Whether `square` closes a trap around `flanked`: it holds the moving piece or one of its
+/// allies, or it is a hostile square not occupied by one of `flanked`'s allies.
+fn is_anvil(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+    square: &Position,
+    flanked: &Piece,
+) -> bool {
+    if square == ctx.target_position {
+        return true;
+    }
+
+    // The source square is vacated by the move.
+    let occupant = ctx
+        .game
+        .piece_at_position(square)
+        .filter(|_| square != ctx.original_source);
+    match occupant {
+        Some(piece) if piece.player == ctx.current_player => true,
+        Some(piece) if piece.player == flanked.player => false,
+        _ => side_effect.hostile.iter().any(|zone| {
+            ctx.game
+                .check_position_condition_for_player(square, zone, ctx.current_player)
+        }),
+    }
+}
@@ -0,0 +1,21 @@
+use crate::logic::blueprint::move_blueprint::SideEffectBlueprint;
+use crate::logic::Piece;
+use crate::shared::BoardChange;
+
+use super::{context::SideEffectContext, helpers};
+
+/// Disables the addressed square, removing any piece on it.
+pub fn apply(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+    _moved_piece: &mut Piece,
+    extra_changes: &mut Vec<BoardChange>,
+) {
+    let Some(position) = helpers::toggled_square(side_effect, ctx) else {
+        return;
+    };
+
+    if !ctx.game.state.disabled_positions.contains(&position) {
+        extra_changes.push(BoardChange::disable(&position));
+    }
+}
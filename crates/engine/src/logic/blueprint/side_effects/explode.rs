@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+
+use crate::logic::blueprint::move_blueprint::SideEffectBlueprint;
+use crate::logic::Piece;
+use crate::shared::{BoardChange, Position};
+
+use super::context::SideEffectContext;
+
+/// Destroys the piece landing on the target square along with every piece, of either side,
+/// within `radius` steps of it (Atomic). `immune` pieces survive the blast unless they are the
+/// landing piece itself.
+pub fn apply(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+    _moved_piece: &mut Piece,
+    extra_changes: &mut Vec<BoardChange>,
+) {
+    extra_changes.push(BoardChange::clear(ctx.target_position));
+
+    let offsets = ctx.game.board.adjacent_offsets();
+    let mut reached: HashSet<Position> = HashSet::from([ctx.target_position.clone()]);
+    let mut ring = vec![ctx.target_position.clone()];
+
+    for _ in 0..side_effect.radius.unwrap_or(1) {
+        ring = ring
+            .iter()
+            .flat_map(|square| offsets.iter().filter_map(|o| ctx.game.offset(square, o)))
+            .filter(|square| reached.insert(square.clone()))
+            .collect();
+
+        for square in &ring {
+            // The source square is vacated by the move.
+            if square == ctx.original_source {
+                continue;
+            }
+            let Some(piece) = ctx.game.piece_at_position(square) else {
+                continue;
+            };
+            if piece.is_neutral() || side_effect.immune.contains(&piece.code) {
+                continue;
+            }
+            extra_changes.push(BoardChange::clear(square));
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
+use crate::logic::blueprint::move_blueprint::SideEffectBlueprint;
 use crate::logic::Game;
-use crate::shared::{into_position, ExtendedPosition, Position};
+use crate::shared::{into_extended_position, ExtendedPosition, Position};
+
+use super::context::SideEffectContext;
 
 pub fn relative_position(
     relative_by_player: Option<&HashMap<String, ExtendedPosition>>,
@@ -10,13 +13,23 @@ pub fn relative_position(
     game: &Game,
 ) -> Option<Position> {
     let relative = relative_by_player?.get(current_player)?;
-    let absolute: Vec<i16> = source_position
-        .iter()
-        .zip(relative.iter())
-        .map(|(&src, &delta)| src as i16 + delta)
-        .collect();
-    if !game.board.is_position_valid(&absolute) {
-        return None;
+    game.offset(source_position, relative)
+}
+
+/// Square addressed by a DISABLE / ENABLE side effect: the relative `target` if present,
+/// otherwise the absolute `square`. Only the board's shape is checked, so disabled squares can
+/// be addressed too.
+pub fn toggled_square(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+) -> Option<Position> {
+    if let Some(target) = &side_effect.target {
+        let relative = target.get(ctx.current_player)?;
+        return ctx.game.board.shift(ctx.source_position, relative);
     }
-    Some(into_position(&absolute))
+
+    let square = side_effect.square.as_ref()?;
+    let on_board = square.len() == ctx.game.board.dimensions.len()
+        && ctx.game.board.contains(&into_extended_position(square));
+    on_board.then(|| square.clone())
 }
@@ -0,0 +1,31 @@
+use crate::logic::blueprint::move_blueprint::SideEffectBlueprint;
+use crate::logic::Piece;
+use crate::shared::BoardChange;
+
+use super::context::SideEffectContext;
+
+/// Converts every enemy run bounded by the landing piece and an ally, in every direction
+/// (Reversi). Converted pieces take the code in `piece`, or keep their own.
+pub fn apply(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+    _moved_piece: &mut Piece,
+    extra_changes: &mut Vec<BoardChange>,
+) {
+    let flips = ctx.game.line_flips(
+        ctx.target_position,
+        ctx.current_player,
+        Some(ctx.original_source),
+    );
+
+    for position in flips {
+        let Some(existing) = ctx.game.piece_at_position(&position) else {
+            continue;
+        };
+        let code = side_effect.piece.clone().unwrap_or(existing.code);
+        extra_changes.push(BoardChange::set_piece(
+            position,
+            Piece::new(code, ctx.current_player.to_string()),
+        ));
+    }
+}
@@ -2,13 +2,23 @@ mod capture;
 pub mod context;
 mod convert;
 mod copy_source;
+mod counter;
+mod custodial;
+mod disable;
+mod enable;
+mod explode;
 mod helpers;
+mod line_flip;
 mod move_piece;
 mod set_state;
+mod variables;
 
 use crate::logic::blueprint::move_blueprint::SideEffectBlueprint;
 use crate::logic::Piece;
-use crate::shared::{BoardChange, CAPTURE, CONVERT, COPY_SOURCE, MOVE, SET_STATE};
+use crate::shared::{
+    BoardChange, VariableChange, CAPTURE, CONVERT, COPY_SOURCE, CUSTODIAL_CAPTURE, DECREMENT,
+    DISABLE, ENABLE, EXPLODE, INCREMENT, LINE_FLIP, MOVE, RESET, SET_STATE,
+};
 
 use context::SideEffectContext;
 
@@ -24,6 +34,23 @@ pub fn apply_side_effect(
         MOVE => move_piece::apply(side_effect, ctx, moved_piece, extra_changes),
         CONVERT => convert::apply(side_effect, ctx, moved_piece, extra_changes),
         COPY_SOURCE => copy_source::apply(side_effect, ctx, moved_piece, extra_changes),
+        DISABLE => disable::apply(side_effect, ctx, moved_piece, extra_changes),
+        ENABLE => enable::apply(side_effect, ctx, moved_piece, extra_changes),
+        CUSTODIAL_CAPTURE => custodial::apply(side_effect, ctx, moved_piece, extra_changes),
+        LINE_FLIP => line_flip::apply(side_effect, ctx, moved_piece, extra_changes),
+        EXPLODE => explode::apply(side_effect, ctx, moved_piece, extra_changes),
+        INCREMENT | DECREMENT | RESET => {
+            counter::apply(side_effect, ctx, moved_piece, extra_changes)
+        }
         _ => {}
     }
 }
+
+/// The game variable change a side effect makes, if it is one of the variable side effects.
+/// These leave the board alone, so `apply_side_effect` ignores them.
+pub fn variable_change(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+) -> Option<VariableChange> {
+    variables::change(side_effect, ctx)
+}
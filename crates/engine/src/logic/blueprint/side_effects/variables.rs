@@ -0,0 +1,30 @@
+use crate::logic::blueprint::move_blueprint::SideEffectBlueprint;
+use crate::shared::{
+    VariableChange, VariableOp, CLEAR_VARIABLE, GLOBAL_SCOPE, INCREMENT_VARIABLE, PLAYER_SCOPE,
+    SET_VARIABLE,
+};
+
+use super::context::SideEffectContext;
+
+/// Builds the game variable change of a SET_VARIABLE, INCREMENT_VARIABLE or CLEAR_VARIABLE side
+/// effect. The change applies to the acting player's variable unless the scope is "GLOBAL".
+/// Returns `None` for every other side effect.
+pub fn change(
+    side_effect: &SideEffectBlueprint,
+    ctx: &SideEffectContext<'_>,
+) -> Option<VariableChange> {
+    let amount = side_effect.amount.unwrap_or(1);
+    let op = match side_effect.action.as_str() {
+        SET_VARIABLE => VariableOp::Set(amount),
+        INCREMENT_VARIABLE => VariableOp::Increment(amount),
+        CLEAR_VARIABLE => VariableOp::Clear,
+        _ => return None,
+    };
+    let name = side_effect.variable.clone()?;
+    let owner = match side_effect.scope.as_deref().unwrap_or(PLAYER_SCOPE) {
+        GLOBAL_SCOPE => None,
+        _ => Some(ctx.current_player.to_string()),
+    };
+
+    Some(VariableChange { owner, name, op })
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::logic::{Game, Piece, Pin, SquareControl};
+use crate::shared::Position;
+
+impl Game {
+    /// Returns the pieces of `player` that attack `position`, sorted by position.
+    ///
+    /// A piece of `player` standing on `position` does not block its own side: the square is
+    /// treated as capturable, so the result also answers "who defends this piece".
+    pub fn attackers_of(&self, position: &Position, player: &str) -> Vec<Position> {
+        let mut simulated = None;
+        if self
+            .state
+            .pieces
+            .get(position)
+            .is_some_and(|p| p.player == player)
+        {
+            // Stand-in owned by nobody, so every player's threats reach the square.
+            let mut pieces = self.state.pieces.clone();
+            pieces.insert(position.clone(), Piece::new(String::new(), String::new()));
+            simulated = Some(pieces);
+        }
+        let pieces = simulated.as_ref().unwrap_or(&self.state.pieces);
+        let view = self.threat_view(player, pieces, &self.state.disabled_positions);
+
+        let mut attackers: Vec<Position> = pieces
+            .iter()
+            .filter(|(pos, p)| p.player == player && *pos != position)
+            .filter(|(pos, p)| {
+                self.blueprints
+                    .get(&p.code)
+                    .is_some_and(|bp| bp.calculate_threats(p, pos, &view).contains(position))
+            })
+            .map(|(pos, _)| pos.clone())
+            .collect();
+        attackers.sort();
+        attackers
+    }
+
+    /// Returns the opponents' attackers and `player`'s own defenders of `position`.
+    pub fn square_control(&self, position: &Position, player: &str) -> SquareControl {
+        let mut attackers: Vec<Position> = self
+            .players
+            .iter()
+            .filter(|p| *p != player)
+            .flat_map(|opponent| self.attackers_of(position, opponent))
+            .collect();
+        attackers.sort();
+
+        SquareControl {
+            attackers,
+            defenders: self.attackers_of(position, player),
+        }
+    }
+
+    /// Returns, for each of `player`'s leaders currently in check, the enemy pieces giving
+    /// that check. Leaders that are not attacked are left out, so an empty map means no check.
+    pub fn checkers(&self, player: &str) -> HashMap<Position, Vec<Position>> {
+        self.leader_positions(player)
+            .into_iter()
+            .filter_map(|leader| {
+                let control = self.square_control(&leader, player);
+                (!control.attackers.is_empty()).then_some((leader, control.attackers))
+            })
+            .collect()
+    }
+
+    /// Returns every piece of `player` that is pinned to one of its leaders, sorted by the
+    /// pinned piece's position.
+    ///
+    /// A piece is pinned when it is the only piece on the line of one of an enemy's moves
+    /// towards the leader, and removing it lets that enemy attack the leader.
+    pub fn pins(&self, player: &str) -> Vec<Pin> {
+        let leaders = self.leader_positions(player);
+        let mut pins: Vec<Pin> = Vec::new();
+
+        let enemies = self
+            .state
+            .pieces
+            .iter()
+            .filter(|(_, p)| p.player != player && !p.is_neutral());
+        for (pinner, enemy) in enemies {
+            let Some(bp) = self.blueprints.get(&enemy.code) else {
+                continue;
+            };
+
+            for mb in &bp.move_blueprints {
+                let Some(step) = mb.step.get(&enemy.player) else {
+                    continue;
+                };
+                if step.iter().all(|s| *s == 0) {
+                    continue;
+                }
+
+                for leader in &leaders {
+                    let Some(line) = self.ray_between(pinner, leader, step) else {
+                        continue;
+                    };
+
+                    // Exactly one piece between pinner and leader, and it must be ours.
+                    let blockers: Vec<&Position> = line
+                        .iter()
+                        .skip(1)
+                        .filter(|pos| self.state.pieces.contains_key(*pos))
+                        .collect();
+                    let [pinned] = blockers.as_slice() else {
+                        continue;
+                    };
+                    if self.state.pieces[*pinned].player != player {
+                        continue;
+                    }
+
+                    let mut without = self.state.pieces.clone();
+                    without.remove(*pinned);
+                    let view =
+                        self.threat_view(&enemy.player, &without, &self.state.disabled_positions);
+                    if !bp.calculate_threats(enemy, pinner, &view).contains(leader) {
+                        continue;
+                    }
+
+                    let pin = Pin {
+                        pinned: (*pinned).clone(),
+                        pinner: pinner.clone(),
+                        leader: leader.clone(),
+                        direction: step.clone(),
+                        line: line.clone(),
+                    };
+                    if !pins.contains(&pin) {
+                        pins.push(pin);
+                    }
+                }
+            }
+        }
+
+        pins.sort_by(|a, b| (&a.pinned, &a.pinner).cmp(&(&b.pinned, &b.pinner)));
+        pins
+    }
+
+    /// Positions of `player`'s leader pieces, sorted.
+    fn leader_positions(&self, player: &str) -> Vec<Position> {
+        let mut leaders: Vec<Position> = self
+            .state
+            .pieces
+            .iter()
+            .filter(|(_, p)| p.player == player && self.leaders(player).contains(&p.code))
+            .map(|(pos, _)| pos.clone())
+            .collect();
+        leaders.sort();
+        leaders
+    }
+
+    /// Walks from `from` by `step` until reaching `to`. Returns the squares visited, starting
+    /// with `from` and excluding `to`, or `None` if `to` is not on that ray.
+    fn ray_between(&self, from: &Position, to: &Position, step: &[i16]) -> Option<Vec<Position>> {
+        let mut line = vec![from.clone()];
+        let mut current = from.clone();
+
+        loop {
+            // On wrapping boards the ray may come back around to `from`.
+            current = self.offset(&current, step).filter(|p| p != from)?;
+            if current == *to {
+                return Some(line);
+            }
+            line.push(current.clone());
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::logic::{Game, GamePhase, Piece};
+use crate::shared::{Effect, Position, CAPTURE, SHOOT};
+
+/// A square a chained piece landed on, with the layout of the board (square, code and owner of
+/// every piece) at that point.
+type ChainStep = (Position, Vec<(Position, String, String)>);
+
+impl Game {
+    /// Returns the enemy pieces `effect` removes: the occupant replaced by the moving piece as
+    /// well as pieces cleared by side effects. A square changed more than once is judged by its
+    /// last change, so a piece put back on its square (e.g. wounded by a shot) is not captured.
+    pub fn captured_pieces(&self, effect: &Effect) -> Vec<Piece> {
+        let player = self.current_player();
+        let mut seen: HashSet<&Position> = HashSet::new();
+        let mut captured: Vec<Piece> = effect
+            .board_changes
+            .iter()
+            .rev()
+            .filter(|change| seen.insert(&change.position))
+            .filter_map(|change| {
+                self.state.pieces.get(&change.position).filter(|existing| {
+                    existing.player != player
+                        && !existing.is_neutral()
+                        && change
+                            .piece
+                            .as_ref()
+                            .is_none_or(|p| p.player != existing.player)
+                })
+            })
+            .cloned()
+            .collect();
+        captured.reverse();
+        captured
+    }
+
+    /// Credits `player` with the enemy pieces `effect` removes (see `captured_pieces`): they go
+    /// to the hand when hands are enabled, demoted where the spec says so, and are tallied for
+    /// the CAPTURE_COUNT win condition. Must run before the effect's board changes are applied.
+    pub(crate) fn record_captures(&mut self, effect: &Effect, player: &str) {
+        let captured = self.captured_pieces(effect);
+
+        if self.hand_enabled {
+            for piece in &captured {
+                let code = self
+                    .demotes_to
+                    .get(&piece.code)
+                    .and_then(|d| d.clone())
+                    .unwrap_or_else(|| piece.code.clone());
+                self.state
+                    .hand
+                    .entry(player.to_string())
+                    .or_default()
+                    .entry(code)
+                    .and_modify(|c| *c = c.saturating_add(1))
+                    .or_insert(1);
+            }
+        }
+
+        for piece in captured {
+            *self
+                .state
+                .captures
+                .entry(player.to_string())
+                .or_default()
+                .entry(piece.code)
+                .or_insert(0) += 1;
+        }
+    }
+
+    /// Returns how many enemy pieces `effect` removes (see `captured_pieces`). A `CAPTURE` or
+    /// `SHOOT` action counts as at least one.
+    pub fn captured_count(&self, effect: &Effect) -> usize {
+        let removed = self.captured_pieces(effect).len();
+        if effect.action == CAPTURE || effect.action == SHOOT {
+            removed.max(1)
+        } else {
+            removed
+        }
+    }
+
+    /// Returns the least number of pieces a move must capture under `mandatory_capture`, or
+    /// `None` when the rule is off or no capture is available, so that any move goes. That is a
+    /// single piece, or the most any move can take when `maximum` is set.
+    pub fn required_captures(&self) -> Option<usize> {
+        let rule = self.mandatory_capture.as_ref()?;
+        let candidates = self.candidate_moves();
+        let mut moves = candidates.iter().flat_map(|(_, moves)| moves);
+
+        if !rule.maximum {
+            return moves
+                .any(|(_, effect)| self.captured_count(effect) > 0)
+                .then_some(1);
+        }
+
+        let best = moves
+            .map(|(to, effect)| self.chain_capture_count(to, effect))
+            .max()
+            .unwrap_or(0);
+        (best > 0).then_some(best)
+    }
+
+    /// Drops the moves that capture fewer than `required` pieces (see `required_captures`).
+    pub(crate) fn retain_required_captures(
+        &self,
+        moves: &mut HashMap<Position, Effect>,
+        required: usize,
+    ) {
+        let maximum = self
+            .mandatory_capture
+            .as_ref()
+            .is_some_and(|rule| rule.maximum);
+
+        moves.retain(|to, effect| {
+            let count = if maximum {
+                self.chain_capture_count(to, effect)
+            } else {
+                self.captured_count(effect)
+            };
+            count >= required
+        });
+    }
+
+    /// Returns true if moving from `from` to `to` is a legal move that was only ruled out
+    /// because a capture is mandatory.
+    pub(crate) fn capture_was_mandatory(&self, from: &Position, to: &Position) -> bool {
+        if self.required_captures().is_none() {
+            return false;
+        }
+
+        self.candidate_moves()
+            .into_iter()
+            .any(|(source, moves)| source == *from && moves.contains_key(to))
+    }
+
+    /// Every legal move of the player to move, by source square, before the mandatory-capture
+    /// rule is applied. Mid-chain only the chained piece's continuations count.
+    fn candidate_moves(&self) -> Vec<(Position, HashMap<Position, Effect>)> {
+        if let GamePhase::Continuing {
+            position, move_ids, ..
+        } = &self.state.phase
+        {
+            return vec![(
+                position.clone(),
+                self.continuation_moves(position, move_ids),
+            )];
+        }
+
+        let player = self.current_player();
+        self.state
+            .pieces
+            .iter()
+            .filter(|(_, piece)| piece.player == player)
+            .filter_map(|(from, piece)| {
+                let moves = self
+                    .blueprints
+                    .get(&piece.code)?
+                    .calculate_moves(piece, from, self)?
+                    .into_iter()
+                    .filter(|(_, effect)| !self.leaves_leader_in_check(effect))
+                    .collect();
+                Some((from.clone(), moves))
+            })
+            .collect()
+    }
+
+    /// Pieces captured by `effect`, plus the most the piece landing on `to` can still take by
+    /// continuing a multi-leg move.
+    fn chain_capture_count(&self, to: &Position, effect: &Effect) -> usize {
+        self.chain_capture_count_visiting(to, effect, &mut HashSet::new())
+    }
+
+    /// `chain_capture_count` along one chain. `visited` holds the landing square and piece
+    /// layout after each leg taken so far. A leg that comes back to one of them closed a loop
+    /// that captured nothing, since every capture changes the layout, so the chain stops there.
+    fn chain_capture_count_visiting(
+        &self,
+        to: &Position,
+        effect: &Effect,
+        visited: &mut HashSet<ChainStep>,
+    ) -> usize {
+        let captured = self.captured_count(effect);
+        let Some(continuation) = &effect.continuation else {
+            return captured;
+        };
+
+        let mut next = self.clone();
+        for change in &effect.board_changes {
+            next.state.apply_change(change);
+        }
+        for change in &effect.variable_changes {
+            next.state.apply_variable_change(change);
+        }
+
+        let mut layout: Vec<(Position, String, String)> = next
+            .state
+            .pieces
+            .iter()
+            .map(|(pos, p)| (pos.clone(), p.code.clone(), p.player.clone()))
+            .collect();
+        layout.sort();
+        let key = (to.clone(), layout);
+        if !visited.insert(key.clone()) {
+            return captured;
+        }
+
+        let further = next
+            .continuation_moves(to, &continuation.move_ids)
+            .iter()
+            .map(|(target, leg)| next.chain_capture_count_visiting(target, leg, visited))
+            .max()
+            .unwrap_or(0);
+        visited.remove(&key);
+        captured + further
+    }
+}
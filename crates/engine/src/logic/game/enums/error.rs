@@ -10,6 +10,7 @@ pub enum GameError {
 
     // Move execution errors
     InvalidMove,
+    CaptureMandatory,
 
     // Transformation errors
     InvalidTransformationOption,
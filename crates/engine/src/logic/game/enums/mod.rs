@@ -1,7 +1,9 @@
 mod error;
 mod phase;
+mod ply;
 mod transition;
 
 pub use error::*;
 pub use phase::*;
+pub use ply::*;
 pub use transition::*;
@@ -22,6 +22,19 @@ pub enum GamePhase {
         piece_code: String,
     },
 
+    // Move made; the same player must now place the neutral piece, showing legal squares.
+    Placing {
+        piece_code: String,
+    },
+
+    // A multi-leg move is under way: the piece on `position` may (or, when `mandatory`, must)
+    // move again using only `move_ids` before the turn ends.
+    Continuing {
+        position: Position,
+        move_ids: Vec<u8>,
+        mandatory: bool,
+    },
+
     // Game is over; winner is Some(player) for checkmate, None for stalemate.
     GameOver {
         winner: Option<String>,
@@ -0,0 +1,37 @@
+use crate::shared::Position;
+use serde::{Deserialize, Serialize};
+
+/// A complete, self-contained player action: everything needed to advance the game
+/// by one turn without further input. Used by search and automated play, where the
+/// multi-step transition flow (select, execute, transform) is driven by `Game::play`.
+///
+/// Serialized untagged, so a move reads `{ "from": [6, 0], "to": [6, 6] }`, a drop reads
+/// `{ "piece_code": "GOLD", "to": [4, 7] }`, a placement reads `{ "to": [3, 4] }` and
+/// stopping a multi-leg move reads `{ "at": [2, 4] }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Ply {
+    // Move the piece at `from` to `to`, resolving any TRANSFORM with `promotion`.
+    Move {
+        from: Position,
+        to: Position,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        promotion: Option<String>,
+    },
+
+    // Drop a piece of `piece_code` from the current player's hand onto `to`.
+    Drop {
+        piece_code: String,
+        to: Position,
+    },
+
+    // Place the game's neutral piece on `to` after a move (Duck Chess).
+    Place {
+        to: Position,
+    },
+
+    // Stop an optional multi-leg move with the piece at `at`, ending the turn.
+    Stop {
+        at: Position,
+    },
+}
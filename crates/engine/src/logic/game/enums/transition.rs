@@ -16,4 +16,10 @@ pub enum GameTransition {
 
     // Execute a drop at the given position (phase must be Dropping)
     ExecuteDrop { position: Position },
+
+    // Place the neutral piece at the given position (phase must be Placing)
+    Place { position: Position },
+
+    // Stop an optional multi-leg move and end the turn (phase must be Continuing)
+    StopChain,
 }
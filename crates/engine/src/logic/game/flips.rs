@@ -0,0 +1,45 @@
+use crate::logic::Game;
+use crate::shared::Position;
+
+impl Game {
+    /// Returns the enemy pieces `player` would flip by landing on `position`: along every line
+    /// through a neighbouring cell, the unbroken run of enemies that ends on one of `player`'s
+    /// pieces. `vacated` is treated as empty, for a piece that leaves it in the same move.
+    pub fn line_flips(
+        &self,
+        position: &Position,
+        player: &str,
+        vacated: Option<&Position>,
+    ) -> Vec<Position> {
+        let mut flips = Vec::new();
+
+        for offset in self.board.adjacent_offsets() {
+            let mut run = Vec::new();
+            let mut cursor = position.clone();
+
+            while let Some(next) = self.offset(&cursor, &offset) {
+                // A line that wraps all the way round is closed by the landing piece itself.
+                if next == *position {
+                    flips.append(&mut run);
+                    break;
+                }
+                let occupant = self
+                    .state
+                    .pieces
+                    .get(&next)
+                    .filter(|_| Some(&next) != vacated);
+                match occupant {
+                    Some(piece) if piece.player == player => {
+                        flips.append(&mut run);
+                        break;
+                    }
+                    Some(piece) if !piece.is_neutral() => run.push(next.clone()),
+                    _ => break,
+                }
+                cursor = next;
+            }
+        }
+
+        flips
+    }
+}
@@ -4,6 +4,13 @@ impl Game {
     /// Calculate all legal drop squares for `piece_code` from the current player's hand.
     /// Transitions to `GamePhase::Dropping` and populates `available_moves`.
     pub fn calculate_drops(&mut self, piece_code: String) -> Result<(), GameError> {
+        if matches!(
+            self.state.phase,
+            GamePhase::Placing { .. } | GamePhase::Continuing { .. }
+        ) {
+            return Err(GameError::InvalidGamePhase);
+        }
+
         let current_player = self.current_player();
 
         // Verify the piece is in the current player's hand with count > 0.
@@ -18,6 +25,11 @@ impl Game {
             return Err(GameError::PieceNotInHand);
         }
 
+        // Drops never capture, so they are out while a capture is available.
+        if self.required_captures().is_some() {
+            return Err(GameError::CaptureMandatory);
+        }
+
         let available = self.compute_drop_squares(&piece_code, &current_player);
 
         self.state.available_moves = Some(available);
@@ -5,6 +5,10 @@ impl Game {
     /// Calculate moves for a specified position.
     /// Move calculation can only happen for the player that's currently playing.
     pub fn calculate_moves(&mut self, position: Position) -> Result<(), GameError> {
+        if matches!(self.state.phase, GamePhase::Placing { .. }) {
+            return Err(GameError::InvalidGamePhase);
+        }
+
         let Some(piece) = self.state.pieces.get(&position) else {
             return Err(GameError::NoPieceInPosition);
         };
@@ -17,8 +21,36 @@ impl Game {
             return Err(GameError::NoAvailableMoves);
         };
 
-        self.state.available_moves = blueprint.calculate_moves(piece, &position, self);
-        self.state.phase = GamePhase::Moving { position };
+        // Mid-chain, only the moving piece may go on, and only with its continuation moves.
+        // The phase stays `Continuing` so that a rejected target does not abandon the chain.
+        let continuing = match &self.state.phase {
+            GamePhase::Continuing {
+                position: chain_position,
+                move_ids,
+                ..
+            } => {
+                if *chain_position != position {
+                    return Err(GameError::InvalidMove);
+                }
+                Some(move_ids.clone())
+            }
+            _ => None,
+        };
+
+        let mut moves = match &continuing {
+            Some(move_ids) => self.continuation_moves(&position, move_ids),
+            None => blueprint
+                .calculate_moves(piece, &position, self)
+                .unwrap_or_default(),
+        };
+        if let Some(required) = self.required_captures() {
+            self.retain_required_captures(&mut moves, required);
+        }
+
+        self.state.available_moves = (!moves.is_empty()).then_some(moves);
+        if continuing.is_none() {
+            self.state.phase = GamePhase::Moving { position };
+        }
         Ok(())
     }
 }
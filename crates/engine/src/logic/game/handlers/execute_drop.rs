@@ -1,5 +1,5 @@
 use crate::logic::{Game, GameError, GamePhase, MoveRecord};
-use crate::shared::DROP;
+use crate::shared::{DROP, UNLIMITED};
 
 impl Game {
     /// Execute a drop at `position`. Phase must be `Dropping`.
@@ -21,21 +21,23 @@ impl Game {
         let effect = effect.clone();
         let current_player = self.current_player();
 
-        // Apply board changes (places the piece on the board).
+        // Pieces removed or taken over by side effects count as captures, as for moves.
+        self.record_captures(&effect, &current_player);
+
+        // Apply board and variable changes (places the piece on the board).
         for change in &effect.board_changes {
-            match &change.piece {
-                Some(p) => {
-                    self.state.pieces.insert(change.position.clone(), p.clone());
-                }
-                None => {
-                    self.state.pieces.remove(&change.position);
-                }
-            }
+            self.state.apply_change(change);
+        }
+        for change in &effect.variable_changes {
+            self.state.apply_variable_change(change);
         }
 
-        // Decrement hand count; remove the entry if it reaches 0.
+        // Decrement hand count; remove the entry if it reaches 0. Unlimited supplies stay.
         if let Some(player_hand) = self.state.hand.get_mut(&current_player) {
-            if let Some(count) = player_hand.get_mut(&piece_code) {
+            let used = player_hand
+                .get_mut(&piece_code)
+                .filter(|count| **count != UNLIMITED);
+            if let Some(count) = used {
                 if *count > 1 {
                     *count -= 1;
                 } else {
@@ -54,9 +56,7 @@ impl Game {
             promotion: None,
         });
 
-        self.next_turn();
-        self.clear_moves();
-        self.check_game_over();
+        self.end_move();
         Ok(())
     }
 }
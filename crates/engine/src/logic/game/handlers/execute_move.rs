@@ -1,37 +1,46 @@
 use crate::logic::{Game, GameError, GamePhase, MoveRecord};
-use crate::shared::{Effect, EffectMetadata, Position, CAPTURE, MOVE, TRANSFORM};
+use crate::shared::{Effect, EffectMetadata, Position, CAPTURE, MOVE, SHOOT, TRANSFORM};
 
 impl Game {
     /// Execute a move that's in the `available_moves` vector.
     pub fn execute_move(&mut self, position: Position) -> Result<(), GameError> {
-        if !matches!(self.state.phase, GamePhase::Moving { .. }) {
-            return Err(GameError::InvalidGamePhase);
-        }
-
         // Extract the source position (set by calculate_moves) before any further borrows.
-        let from = match &self.state.phase {
-            GamePhase::Moving { position: src } => src.clone(),
-            _ => unreachable!(),
+        let (from, continuing) = match &self.state.phase {
+            GamePhase::Moving { position: src } => (src.clone(), false),
+            GamePhase::Continuing { position: src, .. } => (src.clone(), true),
+            _ => return Err(GameError::InvalidGamePhase),
         };
 
         let Some(moves) = &self.state.available_moves else {
+            if self.capture_was_mandatory(&from, &position) {
+                return Err(GameError::CaptureMandatory);
+            }
             return Err(GameError::NoAvailableMoves);
         };
 
         let Some(effect) = moves.get(&position) else {
-            // Move state machine back to move selection phase.
-            self.state.phase = GamePhase::Idle;
-            return Err(GameError::InvalidMove);
+            let error = if self.capture_was_mandatory(&from, &position) {
+                GameError::CaptureMandatory
+            } else {
+                GameError::InvalidMove
+            };
+
+            // Move state machine back to move selection phase. A chain in progress stays put.
+            if !continuing {
+                self.state.phase = GamePhase::Idle;
+            }
+            return Err(error);
         };
 
         // Clone to release the immutable borrow on `self` before the mutable `apply_effect` call.
         let effect = effect.clone();
-        self.apply_effect(&effect, &from, &position);
+        self.apply_effect(&effect, &from, &position, continuing);
         Ok(())
     }
 
     // Apply the effect of a move to the board.
-    fn apply_effect(&mut self, effect: &Effect, from: &Position, to: &Position) {
+    // A continuing leg extends the turn's history record instead of adding a new one.
+    fn apply_effect(&mut self, effect: &Effect, from: &Position, to: &Position, continuing: bool) {
         // Capture piece info for the history record before board changes are applied.
         let (player, piece_code) = self
             .state
@@ -40,77 +49,51 @@ impl Game {
             .map(|p| (p.player.clone(), p.code.clone()))
             .unwrap_or_default();
 
-        // Populate the hand with any opponent pieces being removed, before applying changes.
-        // Two cases:
-        //   1. BoardChange::clear(pos) where an opponent piece sits (en-passant style).
-        //   2. BoardChange::set_piece(pos, ally) where an opponent piece sits (standard capture).
-        if self.hand_enabled {
-            let current_player = player.clone();
-            let captures: Vec<String> = effect
-                .board_changes
-                .iter()
-                .filter_map(|c| {
-                    let is_ally_placement = c
-                        .piece
-                        .as_ref()
-                        .map_or(false, |p| p.player == current_player);
-                    let is_clear = c.piece.is_none();
-                    if !(is_clear || is_ally_placement) {
-                        return None;
-                    }
-                    self.state
-                        .pieces
-                        .get(&c.position)
-                        .filter(|p| p.player != current_player)
-                        .map(|p| {
-                            self.demotes_to
-                                .get(&p.code)
-                                .and_then(|d| d.clone())
-                                .unwrap_or_else(|| p.code.clone())
-                        })
-                })
-                .collect();
-            for code in captures {
-                self.state
-                    .hand
-                    .entry(current_player.clone())
-                    .or_default()
-                    .entry(code)
-                    .and_modify(|c| *c += 1)
-                    .or_insert(1);
-            }
-        }
+        // Fill the hand and the capture tally before the board changes remove the pieces.
+        self.record_captures(effect, &player);
 
         for change in &effect.board_changes {
-            match &change.piece {
-                Some(piece) => {
-                    self.state
-                        .pieces
-                        .insert(change.position.clone(), piece.clone());
-                }
-                None => {
-                    self.state.pieces.remove(&change.position);
+            self.state.apply_change(change);
+        }
+        for change in &effect.variable_changes {
+            self.state.apply_variable_change(change);
+        }
+
+        match self.state.history.last_mut() {
+            // The whole chain is one record: it keeps the first `from` and takes the last `to`.
+            // A plain move leg does not hide an earlier capture.
+            Some(record) if continuing => {
+                record.to = to.clone();
+                if effect.action != MOVE {
+                    record.action = effect.action.clone();
                 }
             }
+            // Append a history record for every concrete action.
+            _ => self.state.history.push(MoveRecord {
+                player,
+                piece_code,
+                from: from.clone(),
+                to: to.clone(),
+                action: effect.action.clone(),
+                promotion: None,
+            }),
         }
 
-        // Append a history record for every concrete action.
-        self.state.history.push(MoveRecord {
-            player,
-            piece_code,
-            from: from.clone(),
-            to: to.clone(),
-            action: effect.action.clone(),
-            promotion: None,
-        });
-
         // Depending on the action, we may need to do different things now.
+        // A shooting piece stays on its source square, so any continuation starts from there.
+        let landed = if effect.action == SHOOT { from } else { to };
         match effect.action.as_str() {
-            MOVE | CAPTURE => {
-                self.next_turn();
-                self.clear_moves();
-                self.check_game_over();
-            }
+            MOVE | CAPTURE | SHOOT => match &effect.continuation {
+                Some(continuation) if self.can_continue(landed, continuation) => {
+                    self.clear_moves();
+                    self.state.phase = GamePhase::Continuing {
+                        position: landed.clone(),
+                        move_ids: continuation.move_ids.clone(),
+                        mandatory: continuation.mandatory,
+                    };
+                }
+                _ => self.end_move(),
+            },
             TRANSFORM => {
                 // Note: this only works if I'm completely certain of the metadata type.
                 // Otherwise this panics.
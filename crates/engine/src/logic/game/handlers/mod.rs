@@ -2,4 +2,7 @@ pub mod calculate_drops;
 pub mod calculate_moves;
 pub mod execute_drop;
 pub mod execute_move;
+pub mod place;
+pub mod play;
+pub mod stop_chain;
 pub mod transform;
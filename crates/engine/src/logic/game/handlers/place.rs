@@ -0,0 +1,48 @@
+use crate::logic::{Game, GameError, GamePhase, MoveRecord};
+use crate::shared::PLACE;
+
+impl Game {
+    /// Place the neutral piece at `position`. Phase must be `Placing`.
+    pub fn place(&mut self, position: Vec<u8>) -> Result<(), GameError> {
+        let piece_code = match &self.state.phase {
+            GamePhase::Placing { piece_code } => piece_code.clone(),
+            _ => return Err(GameError::InvalidGamePhase),
+        };
+
+        let Some(moves) = &self.state.available_moves else {
+            return Err(GameError::NoAvailableMoves);
+        };
+
+        // Unlike other moves, an invalid square keeps the phase: the placement is compulsory.
+        let Some(effect) = moves.get(&position) else {
+            return Err(GameError::InvalidMove);
+        };
+
+        let effect = effect.clone();
+        let from = effect
+            .board_changes
+            .iter()
+            .find(|change| change.piece.is_none())
+            .map(|change| change.position.clone())
+            .unwrap_or_default();
+
+        for change in &effect.board_changes {
+            self.state.apply_change(change);
+        }
+
+        // `from` is an empty Vec when the piece enters the board for the first time.
+        self.state.history.push(MoveRecord {
+            player: self.current_player(),
+            piece_code,
+            from,
+            to: position,
+            action: PLACE.to_string(),
+            promotion: None,
+        });
+
+        self.next_turn();
+        self.clear_moves();
+        self.check_game_over();
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::logic::{Game, GameError, GamePhase, GameTransition, Ply};
+
+impl Game {
+    /// Plays a complete `Ply`, driving the underlying transitions (select, execute and,
+    /// when the move promotes, transform) in one call.
+    pub fn play(&mut self, ply: &Ply) -> Result<(), GameError> {
+        match ply {
+            Ply::Move {
+                from,
+                to,
+                promotion,
+            } => {
+                self.transition(GameTransition::CalculateMoves {
+                    position: from.clone(),
+                })?;
+                self.transition(GameTransition::ExecuteMove {
+                    position: to.clone(),
+                })?;
+
+                if matches!(self.state.phase, GamePhase::Transforming { .. }) {
+                    let Some(target) = promotion.clone() else {
+                        return Err(GameError::InvalidTransformationOption);
+                    };
+                    self.transition(GameTransition::Transform { target })?;
+                }
+                Ok(())
+            }
+            Ply::Drop { piece_code, to } => {
+                self.transition(GameTransition::CalculateDrops {
+                    piece_code: piece_code.clone(),
+                })?;
+                self.transition(GameTransition::ExecuteDrop {
+                    position: to.clone(),
+                })
+            }
+            Ply::Place { to } => self.transition(GameTransition::Place {
+                position: to.clone(),
+            }),
+            Ply::Stop { at } => {
+                match &self.state.phase {
+                    GamePhase::Continuing { position, .. } if position == at => (),
+                    GamePhase::Continuing { .. } => return Err(GameError::InvalidMove),
+                    _ => return Err(GameError::InvalidGamePhase),
+                }
+                self.transition(GameTransition::StopChain)
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::logic::{Game, GameError, GamePhase};
+
+impl Game {
+    /// Ends an optional multi-leg move early. Phase must be `Continuing` and not mandatory.
+    pub fn stop_chain(&mut self) -> Result<(), GameError> {
+        match &self.state.phase {
+            GamePhase::Continuing {
+                mandatory: false, ..
+            } => {
+                // Stopping is a move like any other: it cannot leave the leader in check.
+                if self.leader_in_check() {
+                    return Err(GameError::InvalidMove);
+                }
+                if self.required_captures().is_some() {
+                    return Err(GameError::CaptureMandatory);
+                }
+
+                self.clear_moves();
+                self.end_move();
+                Ok(())
+            }
+            _ => Err(GameError::InvalidGamePhase),
+        }
+    }
+}
@@ -23,9 +23,7 @@ impl Game {
                 }
 
                 // Reset game state
-                self.next_turn();
-                self.clear_moves();
-                self.check_game_over();
+                self.end_move();
                 Ok(())
             }
             _ => Err(GameError::InvalidGamePhase),
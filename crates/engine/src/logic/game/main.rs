@@ -1,15 +1,22 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use crate::shared::{
-    into_string, Position, ALLY, ALLY_ON_FILE, CHECK_COUNT, DROP, EMPTY, ENEMY, NOT_EMPTY,
-    OPPONENT_BARE, PIECE_IN_ZONE, POSITION, STATE,
+    into_position, into_string, BoardChange, Continuation, Effect, EffectMetadata,
+    ExtendedPosition, Position, ALLY, ALLY_ON_FILE, ALL_OF, ANY_OF, CAPTURE, CAPTURE_COUNT,
+    CHECK_COUNT, DROP, EMPTY, ENEMY, EXTINCTION, FLIP_COUNT, GLOBAL_SCOPE, MAJORITY, NOT,
+    NOT_EMPTY, OPPONENT_BARE, OWN_BARE, PIECE_IN_ZONE, PLACE, PLAYER_SCOPE, POSITION, SHOOT, STATE,
+    UNLIMITED, VARIABLE,
 };
-use crate::specs::{ConditionSpec, GameSpec, WinConditionSpec};
+use crate::specs::{GameSpec, MandatoryCaptureSpec, StalemateOutcome, WinConditionSpec};
 use serde_json;
 
 use super::{
-    Board, ConditionDef, GameError, GamePhase, GameState, GameTransition, MoveRecord, Piece,
+    apply_board_change, Board, ConditionDef, GameError, GamePhase, GameState, GameTransition,
+    MoveRecord, Piece, Ply,
 };
+use crate::logic::blueprint::conditions::helpers::{counter_in_bounds, in_bounds};
+use crate::logic::blueprint::move_blueprint::Condition;
 use crate::logic::blueprint::PieceBlueprint;
 
 #[derive(Debug, Clone)]
@@ -23,11 +30,12 @@ pub struct Game {
     // A list of available players. Doubles up as a sort of dynamic enum.
     pub players: Vec<String>,
 
-    // The `board` stores info about dimensions and disabled positions.
+    // The `board` stores info about the board's shape. Disabled squares live in `state`.
     pub board: Board,
 
-    // `blueprints` allow for calculation of piece movements.
-    pub blueprints: HashMap<String, PieceBlueprint>,
+    // `blueprints` allow for calculation of piece movements. They never change after
+    // `from_spec`, so copies of the game share them.
+    pub blueprints: Arc<HashMap<String, PieceBlueprint>>,
 
     // `turn_order` is just a vector specifying the order in which players play,
     // and a cursor is kept to know the current turn.
@@ -40,6 +48,9 @@ pub struct Game {
     // A player is in check if *any* of their leader pieces is attacked.
     pub leader: Vec<String>,
 
+    // Per-player overrides of `leader`. See `leaders`.
+    pub player_leaders: HashMap<String, Vec<String>>,
+
     // Draw condition settings, populated from spec at build time.
     pub repetition_count: Option<u8>,
     pub fifty_move_halfmoves: Option<u16>,
@@ -48,8 +59,8 @@ pub struct Game {
     /// multiset matches any entry has insufficient mating material.
     pub insufficient_material: Vec<Vec<String>>,
 
-    /// When `true`, a player with no legal moves always loses regardless of check status.
-    pub stalemate_loses: bool,
+    /// What happens to a player with no legal moves who is not in check.
+    pub stalemate_outcome: StalemateOutcome,
 
     /// When `true`, captured pieces enter the capturer's hand and can be dropped.
     pub hand_enabled: bool,
@@ -60,6 +71,16 @@ pub struct Game {
 
     /// Win conditions checked after every move, before draw/checkmate detection.
     pub win_conditions: Vec<WinConditionSpec>,
+
+    /// Code of the neutral piece the mover must place after every move, if any.
+    pub placement: Option<String>,
+
+    /// When set, the player to move must capture whenever any capture is available.
+    pub mandatory_capture: Option<MandatoryCaptureSpec>,
+
+    /// Steps along which opposing leaders must not face each other with nothing in between.
+    /// Empty when the `facing_leaders` rule is off.
+    pub facing_leaders: Vec<ExtendedPosition>,
 }
 
 impl Game {
@@ -68,12 +89,14 @@ impl Game {
     // ---------------------------------------------------------------------
     pub fn from_spec(spec: GameSpec) -> Self {
         // Process turn information.
+        let stalemate_outcome = spec.stalemate();
         let turn_order = spec.turns.order;
         let current_turn = spec.turns.start_at;
 
         // Board is created as a smart pointer so that it can later be passed as a reference
         // to each piece without creating circular references.
         let board = Board::from_spec(spec.board);
+        let disabled_positions = board.disabled_positions.clone();
 
         // Create blueprints for each piece & player.
         // TODO: Optimize for pieces that are not direction-dependent.
@@ -91,13 +114,29 @@ impl Game {
         }
 
         // Process player information.
+        let forwards: HashMap<String, ExtendedPosition> = spec
+            .players
+            .iter()
+            .map(|player| (player.name.clone(), player.forward()))
+            .collect();
         let mut players: Vec<String> = Vec::new();
         let mut pieces: HashMap<Position, Piece> = HashMap::new();
+        let mut hand: HashMap<String, HashMap<String, u32>> = HashMap::new();
 
         for player in spec.players.into_iter() {
             // Store players' names (identifiers).
             players.push(player.name.clone());
 
+            // Fill the starting hand.
+            for hand_spec in player.hand {
+                let count = hand_spec.count.unwrap_or(UNLIMITED);
+                if count > 0 {
+                    hand.entry(player.name.clone())
+                        .or_default()
+                        .insert(hand_spec.piece, count);
+                }
+            }
+
             // Add pieces to the board, based on the starting positions for each player.
             for starting_positions in player.starting_positions {
                 let piece_code = starting_positions.piece;
@@ -111,14 +150,35 @@ impl Game {
             }
         }
 
-        // Process custom conditions.
+        // Neutral pieces belong to no player.
+        for starting_positions in spec.neutral_pieces {
+            for position in starting_positions.positions {
+                pieces.insert(position, Piece::neutral(starting_positions.piece.clone()));
+            }
+        }
+
+        // Process custom conditions, expanding POSITION regions into squares for each player.
+        let cells = board.cells();
         let mut conditions: HashMap<String, ConditionDef> = HashMap::new();
         for condition in spec.conditions {
+            let mut check = condition.check;
+            for (player, regions) in condition.regions {
+                let forward = forwards.get(&player).cloned().unwrap_or_default();
+                let squares = check.entry(player).or_default();
+                for region in regions {
+                    squares.extend(
+                        region
+                            .squares(&cells, &board.dimensions, &forward)
+                            .iter()
+                            .map(into_string),
+                    );
+                }
+            }
             conditions.insert(
                 condition.code.clone(),
                 ConditionDef {
                     r#type: condition.r#type,
-                    check: condition.check,
+                    check,
                 },
             );
         }
@@ -140,14 +200,21 @@ impl Game {
             conditions,
             players,
             leader: spec.leader,
+            player_leaders: spec.leaders,
             repetition_count: spec.draw_conditions.repetition_count,
             fifty_move_halfmoves: spec.draw_conditions.fifty_move_halfmoves,
             fifty_move_pawn_codes: spec.draw_conditions.fifty_move_pawn_codes,
             insufficient_material,
-            stalemate_loses: spec.stalemate_loses,
+            stalemate_outcome,
             hand_enabled: spec.hand_enabled,
             demotes_to,
             win_conditions: spec.win_conditions,
+            placement: spec.placement.map(|placement| placement.piece),
+            mandatory_capture: spec.mandatory_capture,
+            facing_leaders: spec
+                .facing_leaders
+                .map(|rule| rule.steps(board.dimensions.len()))
+                .unwrap_or_default(),
             state: GameState {
                 pieces,
                 current_turn,
@@ -155,11 +222,15 @@ impl Game {
                 phase: GamePhase::Idle,
                 history: Vec::new(),
                 position_hashes: Vec::new(),
-                hand: HashMap::new(),
+                hand,
                 check_counts: HashMap::new(),
+                captures: HashMap::new(),
+                disabled_positions,
+                variables: HashMap::new(),
+                player_variables: HashMap::new(),
             },
             board,
-            blueprints,
+            blueprints: Arc::new(blueprints),
         }
     }
 
@@ -174,6 +245,8 @@ impl Game {
             GameTransition::Transform { target } => self.transform(target),
             GameTransition::CalculateDrops { piece_code } => self.calculate_drops(piece_code),
             GameTransition::ExecuteDrop { position } => self.execute_drop(position),
+            GameTransition::Place { position } => self.place(position),
+            GameTransition::StopChain => self.stop_chain(),
         }
     }
 
@@ -196,6 +269,20 @@ impl Game {
         }
     }
 
+    /// Ends the current player's move. Games with a placement hand over to the `Placing` phase
+    /// first; otherwise the turn passes and game over is checked.
+    pub fn end_move(&mut self) {
+        if let Some(piece_code) = self.placement.clone() {
+            self.state.available_moves = Some(self.compute_placement_squares(&piece_code));
+            self.state.phase = GamePhase::Placing { piece_code };
+            return;
+        }
+
+        self.next_turn();
+        self.clear_moves();
+        self.check_game_over();
+    }
+
     pub fn clear_moves(&mut self) {
         self.state.available_moves = None;
     }
@@ -209,14 +296,48 @@ impl Game {
         self.state.pieces.get(position).cloned()
     }
 
+    /// Checks whether a position is on the board and not currently disabled.
+    pub fn is_position_valid(&self, position: &ExtendedPosition) -> bool {
+        self.board.contains(position)
+            && !self
+                .state
+                .disabled_positions
+                .contains(&into_position(position))
+    }
+
+    /// Adds `delta` to `position` on the live board: wraps where the board wraps, and returns
+    /// `None` when the result falls off the board or on a disabled square.
+    pub fn offset(&self, position: &Position, delta: &[i16]) -> Option<Position> {
+        self.board
+            .shift(position, delta)
+            .filter(|target| !self.state.disabled_positions.contains(target))
+    }
+
+    /// Returns every square of the board that is not currently disabled.
+    pub fn all_positions(&self) -> Vec<Position> {
+        let mut positions = self.board.cells();
+        positions.retain(|position| !self.state.disabled_positions.contains(position));
+        positions
+    }
+
     /// Returns the pieces in every player's hand (read-only).
     pub fn hand(&self) -> &HashMap<String, HashMap<String, u32>> {
         &self.state.hand
     }
 
+    /// Value of the game variable `name` as seen by `player`: their own with the "PLAYER" scope
+    /// (the default) or the shared one with "GLOBAL". Unset variables read as 0.
+    pub fn variable(&self, name: &str, scope: Option<&str>, player: &str) -> u16 {
+        let owner = match scope.unwrap_or(PLAYER_SCOPE) {
+            GLOBAL_SCOPE => None,
+            _ => Some(player),
+        };
+        self.state.variable(owner, name)
+    }
+
     /// Returns the set of positions threatened by all pieces belonging to `attacker`.
     pub fn attacked_by(&self, attacker: &str) -> HashSet<Position> {
-        Self::attacked_by_pieces(attacker, &self.state.pieces, &self.board, &self.blueprints)
+        self.attacked_by_pieces(attacker, &self.state.pieces, &self.state.disabled_positions)
     }
 
     /// Returns the player who acted just before the current turn.
@@ -226,50 +347,191 @@ impl Game {
         self.turn_order[idx as usize].clone()
     }
 
-    /// Computes the attack set for `attacker` using an explicit pieces map (for simulation).
+    /// Computes the attack set for `attacker` using explicit pieces and disabled squares (for
+    /// simulation).
     pub(crate) fn attacked_by_pieces(
+        &self,
         attacker: &str,
         pieces: &HashMap<Position, Piece>,
-        board: &Board,
-        blueprints: &HashMap<String, PieceBlueprint>,
+        disabled_positions: &HashSet<Position>,
     ) -> HashSet<Position> {
+        // Threat views carry no blueprints: nothing to compute (and nothing to recurse into).
+        if self.blueprints.is_empty() {
+            return HashSet::new();
+        }
+
+        let view = self.threat_view(attacker, pieces, disabled_positions);
         pieces
             .iter()
             .filter(|(_, p)| p.player == attacker)
-            .filter_map(|(pos, p)| blueprints.get(&p.code).map(|bp| (pos, bp)))
-            .flat_map(|(pos, bp)| bp.calculate_threats_with(attacker, pos, pieces, board))
+            .filter_map(|(pos, p)| self.blueprints.get(&p.code).map(|bp| (pos, p, bp)))
+            .flat_map(|(pos, p, bp)| bp.calculate_threats(p, pos, &view))
             .collect()
     }
 
-    /// Returns true if the current player's leader is in check given a simulated pieces map.
-    /// A player is in check when *any* of their leader-coded pieces is attacked.
-    fn leader_in_check_for_pieces(&self, pieces: &HashMap<Position, Piece>) -> bool {
-        if self.leader.is_empty() {
-            return false;
+    /// A stripped-down copy of the game with `attacker` to move on `pieces` and
+    /// `disabled_positions`, used to run move generation for threat detection. It only takes
+    /// the board, players, conditions and leaders from the game: no blueprints, so attack-based
+    /// conditions (`NOT_ATTACKED`, ...) evaluated inside it see no attacks instead of
+    /// recursing, and none of the live state beyond the game variables.
+    pub(crate) fn threat_view(
+        &self,
+        attacker: &str,
+        pieces: &HashMap<Position, Piece>,
+        disabled_positions: &HashSet<Position>,
+    ) -> Game {
+        Game {
+            name: self.name.clone(),
+            conditions: self.conditions.clone(),
+            players: self.players.clone(),
+            board: self.board.clone(),
+            blueprints: Arc::default(),
+            turn_order: vec![attacker.to_string()],
+            state: GameState {
+                pieces: pieces.clone(),
+                current_turn: 0,
+                available_moves: None,
+                phase: GamePhase::Idle,
+                history: Vec::new(),
+                position_hashes: Vec::new(),
+                hand: HashMap::new(),
+                check_counts: HashMap::new(),
+                captures: HashMap::new(),
+                disabled_positions: disabled_positions.clone(),
+                variables: self.state.variables.clone(),
+                player_variables: self.state.player_variables.clone(),
+            },
+            leader: self.leader.clone(),
+            player_leaders: self.player_leaders.clone(),
+            repetition_count: None,
+            fifty_move_halfmoves: None,
+            fifty_move_pawn_codes: Vec::new(),
+            insufficient_material: Vec::new(),
+            stalemate_outcome: self.stalemate_outcome,
+            hand_enabled: false,
+            demotes_to: HashMap::new(),
+            win_conditions: Vec::new(),
+            placement: None,
+            mandatory_capture: None,
+            facing_leaders: Vec::new(),
         }
+    }
+
+    /// Returns true if the current player's leader is in check given simulated pieces and
+    /// disabled squares.
+    /// A player is in check when *any* of their leader-coded pieces is attacked. Losing a leader
+    /// (e.g. in an explosion) counts as check, while a map that removes the last leader of an
+    /// opponent still in the turn order is never in check: that move ends the game for the
+    /// opponent first. Under `facing_leaders`, a leader facing an opposing one on an open line
+    /// also counts as check.
+    pub(crate) fn leader_in_check_for_pieces(
+        &self,
+        pieces: &HashMap<Position, Piece>,
+        disabled_positions: &HashSet<Position>,
+    ) -> bool {
         let player = self.current_player();
+        if self.leader_lost(&player, pieces) {
+            return true;
+        }
+        if self
+            .turn_order
+            .iter()
+            .any(|opp| *opp != player && self.last_leader_lost(opp, pieces))
+        {
+            return false;
+        }
+
+        let leaders = self.leaders(&player);
+        if leaders.is_empty() {
+            return false;
+        }
         let leader_positions: Vec<Position> = pieces
             .iter()
-            .filter(|(_, p)| p.player == player && self.leader.contains(&p.code))
+            .filter(|(_, p)| p.player == player && leaders.contains(&p.code))
             .map(|(pos, _)| pos.clone())
             .collect();
         if leader_positions.is_empty() {
             return false;
         }
+        if self.leaders_facing(&player, &leader_positions, pieces, disabled_positions) {
+            return true;
+        }
         self.players.iter().filter(|p| **p != player).any(|opp| {
-            let attacks = Self::attacked_by_pieces(opp, pieces, &self.board, &self.blueprints);
+            let attacks = self.attacked_by_pieces(opp, pieces, disabled_positions);
             leader_positions.iter().any(|pos| attacks.contains(pos))
         })
     }
 
+    /// Returns true if a leader of `player`, standing on one of `leader_positions`, sees an
+    /// opposing leader along one of the `facing_leaders` steps with no piece in between.
+    fn leaders_facing(
+        &self,
+        player: &str,
+        leader_positions: &[Position],
+        pieces: &HashMap<Position, Piece>,
+        disabled_positions: &HashSet<Position>,
+    ) -> bool {
+        leader_positions.iter().any(|start| {
+            self.facing_leaders.iter().any(|step| {
+                let mut position = start.clone();
+                // The first piece on the line decides; a wrapped line stops back at the start.
+                while let Some(next) = self
+                    .board
+                    .shift(&position, step)
+                    .filter(|target| !disabled_positions.contains(target))
+                {
+                    if next == *start {
+                        return false;
+                    }
+                    if let Some(piece) = pieces.get(&next) {
+                        return piece.player != player
+                            && !piece.is_neutral()
+                            && self.leaders(&piece.player).contains(&piece.code);
+                    }
+                    position = next;
+                }
+                false
+            })
+        })
+    }
+
+    /// Returns true if `pieces` holds fewer of `player`'s leaders than the live board.
+    fn leader_lost(&self, player: &str, pieces: &HashMap<Position, Piece>) -> bool {
+        let leaders = self.leaders(player);
+        if leaders.is_empty() {
+            return false;
+        }
+        let count = |pieces: &HashMap<Position, Piece>| {
+            pieces
+                .values()
+                .filter(|p| p.player == player && leaders.contains(&p.code))
+                .count()
+        };
+        count(pieces) < count(&self.state.pieces)
+    }
+
+    /// Returns true if `player` has leaders on the board but none left in `pieces`.
+    fn last_leader_lost(&self, player: &str, pieces: &HashMap<Position, Piece>) -> bool {
+        let leaders = self.leaders(player);
+        let is_leader = |p: &Piece| p.player == player && leaders.contains(&p.code);
+        self.state.pieces.values().any(is_leader) && !pieces.values().any(is_leader)
+    }
+
+    /// The leader codes of `player`: their own entry in `player_leaders`, or the shared `leader`.
+    pub fn leaders(&self, player: &str) -> &[String] {
+        self.player_leaders.get(player).unwrap_or(&self.leader)
+    }
+
     /// Returns true if the current player's leader is currently in check.
     pub fn leader_in_check(&self) -> bool {
-        self.leader_in_check_for_pieces(&self.state.pieces)
+        self.leader_in_check_for_pieces(&self.state.pieces, &self.state.disabled_positions)
     }
 
     /// Returns true if the current player has at least one legal move (one that does not
     /// leave their leader in check). Short-circuits on the first legal move found.
     /// When hand_enabled, also checks whether any drop is available.
+    /// `mandatory_capture` never changes the answer: it only narrows the moves down to the
+    /// captures when at least one exists.
     pub fn any_legal_moves(&self) -> bool {
         let player = self.current_player();
 
@@ -286,20 +548,9 @@ impl Game {
                 let Some(moves) = bp.calculate_moves(piece, pos, self) else {
                     return false;
                 };
-                moves.values().any(|effect| {
-                    let mut sim = self.state.pieces.clone();
-                    for change in &effect.board_changes {
-                        match &change.piece {
-                            Some(p) => {
-                                sim.insert(change.position.clone(), p.clone());
-                            }
-                            None => {
-                                sim.remove(&change.position);
-                            }
-                        }
-                    }
-                    !self.leader_in_check_for_pieces(&sim)
-                })
+                moves
+                    .values()
+                    .any(|effect| !self.leaves_leader_in_check(effect))
             });
 
         if has_board_move {
@@ -323,33 +574,183 @@ impl Game {
             })
             .unwrap_or_default();
 
-        for piece_code in &piece_codes {
-            let restrictions = self
-                .blueprints
-                .get(piece_code.as_str())
-                .map(|bp| bp.drop_restrictions.clone())
-                .unwrap_or_default();
+        piece_codes
+            .iter()
+            .any(|piece_code| !self.compute_drop_squares(piece_code, &player).is_empty())
+    }
 
-            for candidate in self.board.all_positions() {
-                if self.state.pieces.contains_key(&candidate) {
-                    continue;
-                }
-                let blocked = restrictions
-                    .iter()
-                    .any(|cond| self.check_drop_restriction(&candidate, cond, &player));
-                if blocked {
+    /// Enumerates every legal `Ply` for the current player: board moves that do not leave
+    /// their leader in check (one per promotion option when the move transforms), followed
+    /// by drops when `hand_enabled`. The order is deterministic so that seeded searches
+    /// are reproducible.
+    pub fn legal_plies(&self) -> Vec<Ply> {
+        if let GamePhase::Placing { piece_code } = &self.state.phase {
+            let mut squares: Vec<Position> = self
+                .compute_placement_squares(piece_code)
+                .into_keys()
+                .collect();
+            squares.sort();
+            return squares.into_iter().map(|to| Ply::Place { to }).collect();
+        }
+
+        // With a capture available under `mandatory_capture`, only captures remain.
+        let required_captures = self.required_captures();
+
+        if let GamePhase::Continuing {
+            position,
+            move_ids,
+            mandatory,
+        } = &self.state.phase
+        {
+            let mut moves = self.continuation_moves(position, move_ids);
+            if let Some(required) = required_captures {
+                self.retain_required_captures(&mut moves, required);
+            }
+            let mut targets: Vec<(Position, Effect)> = moves.into_iter().collect();
+            targets.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let mut plies = Vec::new();
+            for (to, effect) in targets {
+                Self::push_move_plies(&mut plies, position, to, &effect);
+            }
+            if !mandatory && !self.leader_in_check() && required_captures.is_none() {
+                plies.push(Ply::Stop {
+                    at: position.clone(),
+                });
+            }
+            return plies;
+        }
+
+        let player = self.current_player();
+        let mut plies = Vec::new();
+
+        let mut sources: Vec<(&Position, &Piece)> = self
+            .state
+            .pieces
+            .iter()
+            .filter(|(_, piece)| piece.player == player)
+            .collect();
+        sources.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (from, piece) in sources {
+            let Some(bp) = self.blueprints.get(&piece.code) else {
+                continue;
+            };
+            let Some(mut moves) = bp.calculate_moves(piece, from, self) else {
+                continue;
+            };
+            if let Some(required) = required_captures {
+                self.retain_required_captures(&mut moves, required);
+            }
+            let mut targets: Vec<(Position, Effect)> = moves.into_iter().collect();
+            targets.sort_by(|a, b| a.0.cmp(&b.0));
+
+            for (to, effect) in targets {
+                if self.leaves_leader_in_check(&effect) {
                     continue;
                 }
-                let new_piece = Piece::new(piece_code.clone(), player.clone());
-                let mut sim = self.state.pieces.clone();
-                sim.insert(candidate, new_piece);
-                if !self.leader_in_check_for_pieces(&sim) {
-                    return true;
-                }
+                Self::push_move_plies(&mut plies, from, to, &effect);
             }
         }
 
-        false
+        if !self.hand_enabled || required_captures.is_some() {
+            return plies;
+        }
+
+        let mut piece_codes: Vec<String> = self
+            .state
+            .hand
+            .get(&player)
+            .map(|h| {
+                h.iter()
+                    .filter(|(_, &count)| count > 0)
+                    .map(|(code, _)| code.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        piece_codes.sort();
+
+        for piece_code in piece_codes {
+            let mut squares: Vec<Position> = self
+                .compute_drop_squares(&piece_code, &player)
+                .into_keys()
+                .collect();
+            squares.sort();
+            plies.extend(squares.into_iter().map(|to| Ply::Drop {
+                piece_code: piece_code.clone(),
+                to,
+            }));
+        }
+
+        plies
+    }
+
+    /// Pushes the plies for one move: one per promotion option when the move transforms.
+    fn push_move_plies(plies: &mut Vec<Ply>, from: &Position, to: Position, effect: &Effect) {
+        match &effect.metadata {
+            Some(EffectMetadata::Options(options)) => {
+                plies.extend(options.iter().map(|option| Ply::Move {
+                    from: from.clone(),
+                    to: to.clone(),
+                    promotion: Some(option.clone()),
+                }))
+            }
+            None => plies.push(Ply::Move {
+                from: from.clone(),
+                to,
+                promotion: None,
+            }),
+        }
+    }
+
+    /// Computes the moves the piece on `position` can continue a multi-leg move with: those
+    /// among `move_ids` that do not leave the current player's leader in check.
+    pub fn continuation_moves(
+        &self,
+        position: &Position,
+        move_ids: &[u8],
+    ) -> HashMap<Position, Effect> {
+        let Some(piece) = self.state.pieces.get(position) else {
+            return HashMap::new();
+        };
+        let Some(bp) = self.blueprints.get(&piece.code) else {
+            return HashMap::new();
+        };
+
+        bp.calculate_continuation_moves(piece, position, self, move_ids)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, effect)| !self.leaves_leader_in_check(effect))
+            .collect()
+    }
+
+    /// Returns true if the piece that just moved to `position` has a legal way to continue.
+    /// With none, the chain ends with the turn, whether or not it was mandatory.
+    pub(crate) fn can_continue(&self, position: &Position, continuation: &Continuation) -> bool {
+        !self
+            .continuation_moves(position, &continuation.move_ids)
+            .is_empty()
+    }
+
+    /// Returns copies of the current pieces and disabled squares with `effect`'s board changes
+    /// applied, including the squares it disables or enables.
+    pub(crate) fn simulate_effect(
+        &self,
+        effect: &Effect,
+    ) -> (HashMap<Position, Piece>, HashSet<Position>) {
+        let mut pieces = self.state.pieces.clone();
+        let mut disabled_positions = self.state.disabled_positions.clone();
+        for change in &effect.board_changes {
+            apply_board_change(&mut pieces, &mut disabled_positions, change);
+        }
+        (pieces, disabled_positions)
+    }
+
+    /// Returns true if playing `effect` would leave the current player's leader in check, on the
+    /// board the effect produces (see `simulate_effect`).
+    pub(crate) fn leaves_leader_in_check(&self, effect: &Effect) -> bool {
+        let (pieces, disabled_positions) = self.simulate_effect(effect);
+        self.leader_in_check_for_pieces(&pieces, &disabled_positions)
     }
 
     /// Returns true if the drop restriction `cond` fires for the candidate drop square.
@@ -357,12 +758,12 @@ impl Game {
     pub fn check_drop_restriction(
         &self,
         position: &Position,
-        cond: &ConditionSpec,
+        cond: &Condition,
         current_player: &str,
     ) -> bool {
-        match cond.condition.as_str() {
+        match cond.code.as_str() {
             ALLY_ON_FILE => {
-                let Some(ally_code) = &cond.piece else {
+                let Some(ally_code) = &cond.piece_code else {
                     return false;
                 };
                 let file = position[0];
@@ -370,6 +771,31 @@ impl Game {
                     p.player == current_player && p.code == *ally_code && pos[0] == file
                 })
             }
+            FLIP_COUNT => {
+                let count = self.line_flips(position, current_player, None).len();
+                let min = cond.min.unwrap_or(0) as usize;
+                let max = cond.max.unwrap_or(u8::MAX) as usize;
+                count >= min && count <= max
+            }
+            VARIABLE => {
+                let Some(name) = &cond.variable else {
+                    return false;
+                };
+                let value = self.variable(name, cond.scope.as_deref(), current_player);
+                counter_in_bounds(cond, value)
+            }
+            ALL_OF => cond
+                .conditions
+                .iter()
+                .all(|c| self.check_drop_restriction(position, c, current_player)),
+            ANY_OF => cond
+                .conditions
+                .iter()
+                .any(|c| self.check_drop_restriction(position, c, current_player)),
+            NOT => !cond
+                .conditions
+                .iter()
+                .all(|c| self.check_drop_restriction(position, c, current_player)),
             // All other conditions are checked via the custom conditions map (e.g. POSITION).
             other => self.check_position_condition(position, &other.to_string()),
         }
@@ -380,16 +806,14 @@ impl Game {
         &self,
         piece_code: &str,
         current_player: &str,
-    ) -> HashMap<Position, crate::shared::Effect> {
-        use crate::shared::{BoardChange, Effect};
-        let restrictions = self
-            .blueprints
-            .get(piece_code)
+    ) -> HashMap<Position, Effect> {
+        let blueprint = self.blueprints.get(piece_code);
+        let restrictions = blueprint
             .map(|bp| bp.drop_restrictions.clone())
             .unwrap_or_default();
 
         let mut available = HashMap::new();
-        for candidate in self.board.all_positions() {
+        for candidate in self.all_positions() {
             if self.state.pieces.contains_key(&candidate) {
                 continue;
             }
@@ -400,26 +824,67 @@ impl Game {
                 continue;
             }
             let new_piece = Piece::new(piece_code.to_string(), current_player.to_string());
-            let mut sim = self.state.pieces.clone();
-            sim.insert(candidate.clone(), new_piece.clone());
-            if self.leader_in_check_for_pieces(&sim) {
-                continue;
-            }
-            available.insert(
-                candidate.clone(),
-                Effect {
+            let effect = match blueprint {
+                Some(bp) => bp.drop_effect(&new_piece, &candidate, self),
+                None => Effect {
                     action: DROP.to_string(),
-                    board_changes: vec![BoardChange::set_piece(candidate, new_piece)],
+                    board_changes: vec![BoardChange::set_piece(candidate.clone(), new_piece)],
                     metadata: None,
+                    continuation: None,
+                    variable_changes: Vec::new(),
                 },
-            );
+            };
+            if self.leaves_leader_in_check(&effect) {
+                continue;
+            }
+            available.insert(candidate, effect);
+        }
+        available
+    }
+
+    /// Computes every square the neutral `piece_code` can be placed on: any empty square that
+    /// does not leave the current player's leader in check. A piece already on the board is
+    /// lifted from its square, so it always ends up somewhere else.
+    pub fn compute_placement_squares(&self, piece_code: &str) -> HashMap<Position, Effect> {
+        let current: Vec<Position> = self
+            .state
+            .pieces
+            .iter()
+            .filter(|(_, p)| p.is_neutral() && p.code == piece_code)
+            .map(|(pos, _)| pos.clone())
+            .collect();
+
+        let mut available = HashMap::new();
+        for candidate in self.all_positions() {
+            if self.state.pieces.contains_key(&candidate) {
+                continue;
+            }
+
+            let mut board_changes: Vec<BoardChange> =
+                current.iter().map(BoardChange::clear).collect();
+            board_changes.push(BoardChange::set_piece(
+                candidate.clone(),
+                Piece::neutral(piece_code.to_string()),
+            ));
+            let effect = Effect {
+                action: PLACE.to_string(),
+                board_changes,
+                metadata: None,
+                continuation: None,
+                variable_changes: Vec::new(),
+            };
+            if self.leaves_leader_in_check(&effect) {
+                continue;
+            }
+            available.insert(candidate, effect);
         }
         available
     }
 
     /// Builds a deterministic string key encoding the full position:
     /// active player + all pieces sorted by position, with their code, player,
-    /// total_moves, and state flags. Used for repetition detection.
+    /// total_moves, and state flags, + the disabled squares + the game variables. Used for
+    /// repetition detection.
     fn position_key(&self) -> String {
         let mut pieces: Vec<_> = self.state.pieces.iter().collect();
         pieces.sort_by(|a, b| a.0.cmp(b.0));
@@ -441,6 +906,7 @@ impl Game {
                             crate::logic::PieceState::Blank => "B".to_string(),
                             crate::logic::PieceState::Uint(n) => format!("U{n}"),
                             crate::logic::PieceState::String(s) => format!("S{s}"),
+                            crate::logic::PieceState::Counter(n) => format!("C{n}"),
                         };
                         format!("{k}={v_s}")
                     })
@@ -455,7 +921,38 @@ impl Game {
             })
             .collect();
 
-        format!("{}|{}", self.current_player(), pieces_str)
+        let mut disabled: Vec<_> = self.state.disabled_positions.iter().collect();
+        disabled.sort();
+        let disabled_str: String = disabled
+            .iter()
+            .map(|pos| format!("[{}]", into_string(pos)))
+            .collect();
+
+        let mut variables: Vec<String> = self
+            .state
+            .variables
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .chain(
+                self.state
+                    .player_variables
+                    .iter()
+                    .flat_map(|(player, values)| {
+                        values
+                            .iter()
+                            .map(move |(name, value)| format!("{player}:{name}={value}"))
+                    }),
+            )
+            .collect();
+        variables.sort();
+
+        format!(
+            "{}|{}|{}|{}",
+            self.current_player(),
+            pieces_str,
+            disabled_str,
+            variables.join(";")
+        )
     }
 
     /// Records the current position and checks all configured draw conditions.
@@ -480,11 +977,19 @@ impl Game {
 
         // Fifty-move rule (N consecutive half-moves with no pawn push and no capture).
         if let Some(halfmoves) = self.fifty_move_halfmoves {
-            let h = &self.state.history;
+            // Placements belong to the move before them and do not count as half-moves.
+            let h: Vec<&MoveRecord> = self
+                .state
+                .history
+                .iter()
+                .filter(|r| r.action != PLACE)
+                .collect();
             if h.len() >= halfmoves as usize {
                 let recent = &h[h.len() - halfmoves as usize..];
                 let no_reset = recent.iter().all(|r| {
-                    r.action != "CAPTURE" && !self.fifty_move_pawn_codes.contains(&r.piece_code)
+                    r.action != CAPTURE
+                        && r.action != SHOOT
+                        && !self.fifty_move_pawn_codes.contains(&r.piece_code)
                 });
                 if no_reset {
                     self.state.phase = GamePhase::GameOver { winner: None };
@@ -528,29 +1033,106 @@ impl Game {
         if self.check_draws() {
             return;
         }
-        if !self.any_legal_moves() {
-            let is_checkmate = self.stalemate_loses || self.leader_in_check();
-            if is_checkmate {
-                // Checkmate (or stalemate-loses): eliminate the current player.
-                let eliminated = self.current_player();
-                self.turn_order.retain(|p| *p != eliminated);
-                if self.turn_order.len() <= 1 {
-                    let winner = self.turn_order.first().cloned();
-                    self.state.phase = GamePhase::GameOver { winner };
-                } else {
-                    // Clamp the cursor in case it ran past the new end of the vector.
-                    self.state.current_turn %= self.turn_order.len() as u8;
-                    self.state.phase = GamePhase::Idle;
-                }
-            } else {
-                // Stalemate: draw.
-                self.state.phase = GamePhase::GameOver { winner: None };
+        if self.any_legal_moves() {
+            self.state.phase = GamePhase::Idle;
+            return;
+        }
+
+        if self.leader_in_check() {
+            self.eliminate_current_player();
+            return;
+        }
+
+        match self.stalemate_outcome {
+            StalemateOutcome::Loss => self.eliminate_current_player(),
+            StalemateOutcome::Win => {
+                self.state.phase = GamePhase::GameOver {
+                    winner: Some(self.current_player()),
+                };
             }
+            StalemateOutcome::Draw if self.turn_order.len() <= 2 => self.end_in_stalemate(),
+            StalemateOutcome::Draw | StalemateOutcome::Pass => self.pass_stalemated_turns(),
+        }
+    }
+
+    /// Removes the current player from the turn order (checkmate, or stalemate as a loss).
+    /// The last player left wins.
+    fn eliminate_current_player(&mut self) {
+        let eliminated = self.current_player();
+        self.eliminate_players(&[eliminated]);
+    }
+
+    /// Removes `losers` from the turn order. The turn passes to the first remaining player at or
+    /// after the cursor; the last player left wins, and the game is drawn if nobody is left.
+    fn eliminate_players(&mut self, losers: &[String]) {
+        let len = self.turn_order.len();
+        let cursor = self.state.current_turn as usize;
+        let next = (0..len)
+            .map(|i| &self.turn_order[(cursor + i) % len])
+            .find(|p| !losers.contains(p))
+            .cloned();
+
+        self.turn_order.retain(|p| !losers.contains(p));
+        if self.turn_order.len() <= 1 {
+            let winner = self.turn_order.first().cloned();
+            self.state.phase = GamePhase::GameOver { winner };
         } else {
+            self.state.current_turn = next
+                .and_then(|next| self.turn_order.iter().position(|p| *p == next))
+                .unwrap_or(0) as u8;
             self.state.phase = GamePhase::Idle;
         }
     }
 
+    /// Stalemated players keep their turn slot but pass, and the game only ends once nobody can
+    /// move. A player found checkmated while passing is eliminated.
+    fn pass_stalemated_turns(&mut self) {
+        for _ in 1..self.turn_order.len() {
+            self.next_turn();
+            if self.any_legal_moves() {
+                self.state.phase = GamePhase::Idle;
+                return;
+            }
+            if self.leader_in_check() {
+                self.eliminate_current_player();
+                return;
+            }
+        }
+
+        self.end_in_stalemate();
+    }
+
+    /// Ends the game in stalemate: drawn, unless a `MAJORITY` win condition awards it to the
+    /// player with the most pieces on the board. A tie for the most pieces is still a draw.
+    fn end_in_stalemate(&mut self) {
+        let majority = self.win_conditions.iter().find(|wc| wc.r#type == MAJORITY);
+        let winner = majority.and_then(|wc| {
+            let counts: Vec<(String, usize)> = self
+                .turn_order
+                .iter()
+                .filter(|player| wc.applies_to(player))
+                .map(|player| {
+                    let count = self
+                        .state
+                        .pieces
+                        .values()
+                        .filter(|p| p.player == *player)
+                        .filter(|p| wc.piece.as_ref().is_none_or(|code| p.code == *code))
+                        .count();
+                    (player.clone(), count)
+                })
+                .collect();
+            let most = counts.iter().map(|(_, count)| *count).max()?;
+            let mut leaders = counts.into_iter().filter(|(_, count)| *count == most);
+            match (leaders.next(), leaders.next()) {
+                (Some((player, _)), None) => Some(player),
+                _ => None,
+            }
+        });
+
+        self.state.phase = GamePhase::GameOver { winner };
+    }
+
     /// Serializes the mutable runtime state to a JSON string.
     /// The spec-derived structure (blueprints, board, players, etc.) is not included;
     /// restore by calling `restore_state` on a `Game` built from the same spec.
@@ -560,8 +1142,16 @@ impl Game {
 
     /// Replaces the current game state with one previously produced by `save_state`.
     /// `available_moves` will be `None` after restore; call `CalculateMoves` to repopulate it.
+    /// States saved before squares could be disabled during play have no `disabled_positions`:
+    /// they start from the spec's disabled squares.
     pub fn restore_state(&mut self, json: &str) -> Result<(), serde_json::Error> {
-        self.state = serde_json::from_str(json)?;
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let has_disabled_positions = value.get("disabled_positions").is_some();
+
+        self.state = serde_json::from_value(value)?;
+        if !has_disabled_positions {
+            self.state.disabled_positions = self.board.disabled_positions.clone();
+        }
         Ok(())
     }
 
@@ -593,7 +1183,7 @@ impl Game {
                 if condition == ALLY && p.player == player {
                     return true;
                 }
-                if condition == ENEMY && p.player != player {
+                if condition == ENEMY && p.player != player && !p.is_neutral() {
                     return true;
                 }
             }
@@ -633,6 +1223,9 @@ impl Game {
                     let (Some(piece_code), Some(zone)) = (&wc.piece, &wc.zone) else {
                         continue;
                     };
+                    if !wc.applies_to(&prev_player) {
+                        continue;
+                    }
                     let positions: Vec<Position> = self
                         .state
                         .pieces
@@ -652,6 +1245,9 @@ impl Game {
                 }
 
                 OPPONENT_BARE => {
+                    if !wc.applies_to(&prev_player) {
+                        continue;
+                    }
                     let won = self
                         .players
                         .iter()
@@ -672,6 +1268,9 @@ impl Game {
                 }
 
                 CHECK_COUNT => {
+                    if !wc.applies_to(&prev_player) {
+                        continue;
+                    }
                     let threshold = wc.threshold.unwrap_or(3);
                     // If the current player (who is now about to move) is in check,
                     // the previous player (who just moved) delivered that check.
@@ -691,10 +1290,116 @@ impl Game {
                     }
                 }
 
+                OWN_BARE => {
+                    let bare = self
+                        .turn_order_from(&prev_player)
+                        .into_iter()
+                        .filter(|player| wc.applies_to(player))
+                        .find(|player| {
+                            self.state
+                                .pieces
+                                .values()
+                                .filter(|p| p.player == *player)
+                                .all(|p| wc.exempt.contains(&p.code))
+                        });
+                    if let Some(winner) = bare {
+                        self.state.phase = GamePhase::GameOver {
+                            winner: Some(winner),
+                        };
+                        return true;
+                    }
+                }
+
+                EXTINCTION => {
+                    let extinct: Vec<String> = self
+                        .turn_order
+                        .iter()
+                        .filter(|player| wc.applies_to(player))
+                        .filter(|player| {
+                            wc.pieces.iter().any(|code| {
+                                !self
+                                    .state
+                                    .pieces
+                                    .values()
+                                    .any(|p| p.player == **player && p.code == *code)
+                            })
+                        })
+                        .cloned()
+                        .collect();
+                    if !extinct.is_empty() {
+                        self.eliminate_players(&extinct);
+                        if matches!(self.state.phase, GamePhase::GameOver { .. }) {
+                            return true;
+                        }
+                    }
+                }
+
+                CAPTURE_COUNT => {
+                    let threshold = wc.threshold.unwrap_or(1);
+                    let reached = self
+                        .turn_order_from(&prev_player)
+                        .into_iter()
+                        .filter(|player| wc.applies_to(player))
+                        .find(|player| {
+                            let count: u32 = self
+                                .state
+                                .captures
+                                .get(player)
+                                .map(|codes| {
+                                    codes
+                                        .iter()
+                                        .filter(|(code, _)| {
+                                            wc.piece.as_ref().is_none_or(|p| p == *code)
+                                        })
+                                        .map(|(_, count)| count)
+                                        .sum()
+                                })
+                                .unwrap_or(0);
+                            count >= threshold
+                        });
+                    if let Some(winner) = reached {
+                        self.state.phase = GamePhase::GameOver {
+                            winner: Some(winner),
+                        };
+                        return true;
+                    }
+                }
+
+                VARIABLE => {
+                    let Some(name) = &wc.variable else {
+                        continue;
+                    };
+                    let threshold = wc.threshold.unwrap_or(1);
+                    let reached = self
+                        .turn_order_from(&prev_player)
+                        .into_iter()
+                        .filter(|player| wc.applies_to(player))
+                        .find(|player| {
+                            let value = self.variable(name, Some(PLAYER_SCOPE), player);
+                            in_bounds(value.into(), threshold, None)
+                        });
+                    if let Some(winner) = reached {
+                        self.state.phase = GamePhase::GameOver {
+                            winner: Some(winner),
+                        };
+                        return true;
+                    }
+                }
+
                 _ => {}
             }
         }
 
         false
     }
+
+    /// Players in turn order starting with `first`, so that the mover is checked before the
+    /// others when a win condition could fire for several players at once.
+    fn turn_order_from(&self, first: &str) -> Vec<String> {
+        let start = self.turn_order.iter().position(|p| p == first).unwrap_or(0);
+        let len = self.turn_order.len();
+        (0..len)
+            .map(|i| self.turn_order[(start + i) % len].clone())
+            .collect()
+    }
 }
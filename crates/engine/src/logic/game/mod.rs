@@ -1,4 +1,7 @@
+pub mod attack_map;
+pub mod captures;
 pub mod enums;
+pub mod flips;
 pub mod handlers;
 pub mod main;
 pub mod structs;
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::{ExtendedPosition, Position};
+
+/// Who covers a square, from the point of view of one player.
+///
+/// `attackers` are opponent pieces that could capture on the square; `defenders` are the
+/// player's own pieces that could recapture there. Both lists are sorted by position.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SquareControl {
+    pub attackers: Vec<Position>,
+    pub defenders: Vec<Position>,
+}
+
+impl SquareControl {
+    pub fn attack_count(&self) -> usize {
+        self.attackers.len()
+    }
+
+    pub fn defense_count(&self) -> usize {
+        self.defenders.len()
+    }
+}
+
+/// A piece that cannot leave the line between an enemy attacker and its own leader
+/// without exposing the leader.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pin {
+    // The pinned piece.
+    pub pinned: Position,
+
+    // The enemy piece doing the pinning.
+    pub pinner: Position,
+
+    // The leader being shielded.
+    pub leader: Position,
+
+    // Step of the pinner's move along the pin, pointing from `pinner` towards `leader`.
+    pub direction: ExtendedPosition,
+
+    // Squares the pinned piece may still occupy: from the pinner (inclusive) to the
+    // leader (exclusive).
+    pub line: Vec<Position>,
+}
@@ -1,14 +1,24 @@
-use crate::shared::{into_position, ExtendedPosition, Position};
+use crate::shared::{into_position, on_hex, ExtendedPosition, Position, HEX, HEX_ORTHOGONALS};
 use crate::specs::BoardSpec;
 use std::collections::HashSet;
+use std::ops::RangeInclusive;
 
 /// A `Board` is a representation of everything board-related. Of course,
 /// boards contain pieces, and have a shape that establishes which positions
-/// are viable.
+/// are viable. Squares disabled during play live in `GameState`; `Game` combines
+/// both through `is_position_valid`, `offset` and `all_positions`.
 #[derive(Clone, Debug)]
 pub struct Board {
+    // Cell shape: "SQUARE" or "HEX" (axial coordinates).
+    pub kind: String,
+
     // Board shape specifications
     pub dimensions: Vec<u8>,
+
+    // Per-axis flag: steps leaving that axis on one edge reappear on the opposite one.
+    pub wrap: Vec<bool>,
+
+    // Squares disabled when the game starts. The live set is `GameState::disabled_positions`.
     pub disabled_positions: HashSet<Position>,
 }
 
@@ -18,7 +28,9 @@ pub struct Board {
 impl Board {
     pub fn from_spec(board_spec: BoardSpec) -> Board {
         Board {
+            kind: board_spec.kind,
             dimensions: board_spec.dimensions,
+            wrap: board_spec.wrap,
             disabled_positions: board_spec.disabled_positions,
         }
     }
@@ -28,41 +40,114 @@ impl Board {
 // Logic-related associated fns
 // ---------------------------------------------------------------------
 impl Board {
-    /// Returns every valid (non-disabled, in-bounds) position on the board.
-    /// Only works for 2-D boards; returns an empty Vec for other dimensionalities.
-    pub fn all_positions(&self) -> Vec<Position> {
-        if self.dimensions.len() != 2 {
-            return vec![];
-        }
-        let cols = self.dimensions[0];
-        let rows = self.dimensions[1];
-        let mut result = Vec::with_capacity((cols as usize) * (rows as usize));
-        for col in 0..cols {
-            for row in 0..rows {
-                let pos: Position = vec![col, row];
-                if !self.disabled_positions.contains(&pos) {
-                    result.push(pos);
-                }
-            }
-        }
-        result
+    pub fn is_hex(&self) -> bool {
+        self.kind == HEX
     }
 
-    /// Checks whether if a position is valid by examining out-of-bounds conditions
-    /// and disabled positions.
-    pub fn is_position_valid(&self, position: &ExtendedPosition) -> bool {
-        for i in 0..position.len() {
-            if position[i] < 0 || position[i] > self.dimensions[i] as i16 - 1i16 {
+    /// Returns every cell within the board's shape, for any number of dimensions, ignoring
+    /// disabled squares. Positions are ordered with the first coordinate varying slowest.
+    pub fn cells(&self) -> Vec<Position> {
+        grid(self.dimensions.iter().map(|&size| 0..=size as i16 - 1))
+            .iter()
+            .filter(|position| !position.is_empty() && self.contains(position))
+            .map(into_position)
+            .collect()
+    }
+
+    /// Checks whether a position lies within the board's shape by examining out-of-bounds
+    /// conditions (including the hexagon's edges on hex boards).
+    pub fn contains(&self, position: &ExtendedPosition) -> bool {
+        for (&coordinate, &size) in position.iter().zip(self.dimensions.iter()) {
+            if coordinate < 0 || coordinate > size as i16 - 1i16 {
                 // Value is outside of range.
                 return false;
             }
+        }
 
-            if self.disabled_positions.contains(&into_position(position)) {
-                // Value is in one of the known disabled positions.
-                return false;
+        // Value may be in one of the corners cut off by the hexagon.
+        !self.is_hex() || on_hex(position, self.dimensions[0])
+    }
+
+    /// Whether steps along `axis` wrap around the board's edges.
+    pub fn wraps(&self, axis: usize) -> bool {
+        self.wrap.get(axis).copied().unwrap_or(false)
+    }
+
+    /// Adds `delta` to `position`, wrapping around the axes that wrap. Returns `None` when the
+    /// result falls off the board.
+    pub fn shift(&self, position: &Position, delta: &[i16]) -> Option<Position> {
+        let target: ExtendedPosition = position
+            .iter()
+            .zip(delta.iter())
+            .zip(self.dimensions.iter())
+            .enumerate()
+            .map(|(axis, ((&coordinate, &delta), &size))| {
+                let moved = coordinate as i16 + delta;
+                if self.wraps(axis) {
+                    moved.rem_euclid(size as i16)
+                } else {
+                    moved
+                }
+            })
+            .collect();
+
+        self.contains(&target).then(|| into_position(&target))
+    }
+
+    /// Offsets of the cells touching a cell: every combination of -1, 0 and 1 per axis except
+    /// the cell itself (8 on a square board, 26 on a cube), or the 6 hex neighbours.
+    pub fn adjacent_offsets(&self) -> Vec<ExtendedPosition> {
+        if self.is_hex() {
+            return HEX_ORTHOGONALS.iter().map(|step| step.to_vec()).collect();
+        }
+
+        let mut offsets = grid(self.dimensions.iter().map(|_| -1..=1));
+        offsets.retain(|offset| offset.iter().any(|&delta| delta != 0));
+        offsets
+    }
+
+    /// Splits `step` into a unit step and a count, so that the cells strictly between a
+    /// source and `source + step` are `source + unit * k` for `k` in `1..count`.
+    ///
+    /// Square boards take the sign of each coordinate (`[0, 2]` → `[0, 1]` × 2). Hex boards
+    /// divide by the common factor instead, so a single hex diagonal such as `[2, -1]` has no
+    /// cells in between, while `[2, 2]` passes through `[1, 1]`.
+    pub fn path_unit(&self, step: &[i16]) -> (ExtendedPosition, i16) {
+        if self.is_hex() {
+            let count = step.iter().fold(0, |acc, &c| gcd(acc, c.abs()));
+            if count == 0 {
+                return (step.to_vec(), 0);
             }
+            return (step.iter().map(|&c| c / count).collect(), count);
         }
 
-        true
+        let unit = step.iter().map(|&delta| delta.signum()).collect();
+        let count = step.iter().map(|&delta| delta.abs()).max().unwrap_or(0);
+        (unit, count)
+    }
+}
+
+/// Every point of the grid spanned by one coordinate range per axis, with the first axis
+/// varying slowest.
+fn grid(axes: impl Iterator<Item = RangeInclusive<i16>>) -> Vec<ExtendedPosition> {
+    axes.fold(vec![vec![]], |points, axis| {
+        points
+            .into_iter()
+            .flat_map(|prefix| {
+                axis.clone().map(move |coordinate| {
+                    let mut point = prefix.clone();
+                    point.push(coordinate);
+                    point
+                })
+            })
+            .collect()
+    })
+}
+
+fn gcd(a: i16, b: i16) -> i16 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
     }
 }
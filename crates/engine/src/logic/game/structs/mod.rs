@@ -1,9 +1,11 @@
+pub mod attack;
 pub mod board;
 pub mod condition;
 pub mod move_record;
 pub mod piece;
 pub mod state;
 
+pub use attack::*;
 pub use board::*;
 pub use condition::*;
 pub use move_record::*;
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::shared::NEUTRAL;
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Piece {
     pub code: String,
@@ -23,11 +25,16 @@ pub struct Piece {
 /// # Permanent flags
 /// `Blank` and `String` variants are never decremented and persist until
 /// explicitly removed by a side-effect.
+///
+/// # Counters (`Counter`)
+/// `PieceState::Counter(N)` is a named tally that only changes through the `INCREMENT`,
+/// `DECREMENT` and `RESET` side effects. A missing counter reads as `0`.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum PieceState {
     Blank,
     Uint(u16),
     String(String),
+    Counter(u16),
 }
 
 impl Piece {
@@ -40,6 +47,33 @@ impl Piece {
         }
     }
 
+    /// Creates a piece that belongs to no player. Nobody can move or capture it.
+    pub fn neutral(code: String) -> Self {
+        Piece::new(code, NEUTRAL.to_string())
+    }
+
+    pub fn is_neutral(&self) -> bool {
+        self.player == NEUTRAL
+    }
+
+    /// Returns the value of the counter `name`, or `0` if the piece has none.
+    pub fn counter(&self, name: &str) -> u16 {
+        match self.state.get(name) {
+            Some(PieceState::Counter(n)) => *n,
+            _ => 0,
+        }
+    }
+
+    /// Sets the counter `name` to `value`. A counter set to `0` is removed.
+    pub fn set_counter(&mut self, name: &str, value: u16) {
+        if value == 0 {
+            self.state.remove(name);
+        } else {
+            self.state
+                .insert(name.to_string(), PieceState::Counter(value));
+        }
+    }
+
     /// Ticks all duration-tracked (`Uint`) state flags by one step.
     /// Flags already at `0` are removed; flags at `N > 0` become `N - 1`.
     /// Called once per `next_turn()`.
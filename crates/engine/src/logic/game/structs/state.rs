@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::logic::GamePhase;
 use crate::logic::MoveRecord;
 use crate::logic::Piece;
-use crate::shared::{Effect, Position};
+use crate::shared::{BoardChange, Effect, Position, VariableChange, VariableOp};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
@@ -40,6 +40,91 @@ pub struct GameState {
     // player → number of checks that player has delivered to opponents.
     #[serde(default)]
     pub check_counts: HashMap<String, u32>,
+
+    // Pieces each player has captured: player → piece_code → count.
+    // Used by the CAPTURE_COUNT win condition.
+    #[serde(default)]
+    pub captures: HashMap<String, HashMap<String, u32>>,
+
+    // Squares that cannot be entered. Starts as the spec's `disabled_positions` and changes
+    // through the DISABLE and ENABLE side effects.
+    #[serde(default)]
+    pub disabled_positions: HashSet<Position>,
+
+    // Game variables changed by the variable side effects: name → value for the global ones,
+    // player → name → value for the per-player ones. A missing variable reads as 0.
+    #[serde(default)]
+    pub variables: HashMap<String, u16>,
+    #[serde(default)]
+    pub player_variables: HashMap<String, HashMap<String, u16>>,
+}
+
+impl GameState {
+    /// Applies a single board change: places or removes the piece, then disables or enables
+    /// the square if the change asks for it.
+    pub fn apply_change(&mut self, change: &BoardChange) {
+        apply_board_change(&mut self.pieces, &mut self.disabled_positions, change);
+    }
+
+    /// Value of a game variable: the global one when `owner` is `None`, otherwise the player's.
+    pub fn variable(&self, owner: Option<&str>, name: &str) -> u16 {
+        let values = match owner {
+            Some(player) => self.player_variables.get(player),
+            None => Some(&self.variables),
+        };
+        values
+            .and_then(|values| values.get(name))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Applies a single variable change. Variables that drop back to 0 are removed.
+    pub fn apply_variable_change(&mut self, change: &VariableChange) {
+        let value = match change.op {
+            VariableOp::Set(value) => value,
+            VariableOp::Increment(amount) => self
+                .variable(change.owner.as_deref(), &change.name)
+                .saturating_add(amount),
+            VariableOp::Clear => 0,
+        };
+
+        let values = match &change.owner {
+            Some(player) => self.player_variables.entry(player.clone()).or_default(),
+            None => &mut self.variables,
+        };
+        if value == 0 {
+            values.remove(&change.name);
+        } else {
+            values.insert(change.name.clone(), value);
+        }
+    }
+}
+
+/// Applies a single board change to `pieces` and `disabled_positions`, either the live ones or
+/// a simulated copy (see `Game::simulate_effect`).
+pub(crate) fn apply_board_change(
+    pieces: &mut HashMap<Position, Piece>,
+    disabled_positions: &mut HashSet<Position>,
+    change: &BoardChange,
+) {
+    match &change.piece {
+        Some(piece) => {
+            pieces.insert(change.position.clone(), piece.clone());
+        }
+        None => {
+            pieces.remove(&change.position);
+        }
+    }
+
+    match change.enabled {
+        Some(true) => {
+            disabled_positions.remove(&change.position);
+        }
+        Some(false) => {
+            disabled_positions.insert(change.position.clone());
+        }
+        None => {}
+    }
 }
 
 /// Serde module for `HashMap<Position, V>` where `Position = Vec<u8>`.
@@ -1,4 +1,6 @@
 pub mod blueprint;
 pub mod game;
+pub mod puzzle;
 
 pub use game::*;
+pub use puzzle::*;
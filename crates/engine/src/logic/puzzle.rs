@@ -0,0 +1,199 @@
+use crate::logic::{Game, GamePhase, Piece, Ply};
+use crate::search::{MateResult, MateSolver};
+use crate::shared::{into_extended_position, Position, MATE};
+use crate::specs::PuzzleSpec;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PuzzleStatus {
+    // Waiting for the user's next move.
+    InProgress,
+
+    // The user played through the whole solution (or forced the win early).
+    Solved,
+
+    // The user played a legal move that is not part of the solution.
+    Failed,
+}
+
+#[derive(Debug)]
+pub enum PuzzleError {
+    // Setup errors
+    UnknownPlayer(String),
+    InvalidPosition(Position),
+    InvalidSolution(usize),
+
+    // Play errors
+    NotInProgress,
+    IllegalMove,
+}
+
+/// Drives a `PuzzleSpec` on top of a `Game`: the user submits plies for `to_move`, each one is
+/// checked against the solution, and the opponent replies from the solution are played
+/// automatically.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    pub spec: PuzzleSpec,
+    pub game: Game,
+
+    // The line being followed. Starts as `spec.solution`; for "MATE" puzzles it is rewritten
+    // with the solver's main line when the user finds an alternative.
+    solution: Vec<Ply>,
+
+    // Player making each ply of `solution`.
+    movers: Vec<String>,
+
+    // Index of the next ply in `solution`.
+    cursor: usize,
+
+    status: PuzzleStatus,
+}
+
+impl Puzzle {
+    /// Sets `game` (built from the spec named in `spec.spec`) up at the puzzle position and
+    /// checks that the solution line is legal from there.
+    pub fn new(spec: PuzzleSpec, mut game: Game) -> Result<Self, PuzzleError> {
+        game.state.pieces.clear();
+        for (player, entries) in &spec.position {
+            if !game.players.contains(player) {
+                return Err(PuzzleError::UnknownPlayer(player.clone()));
+            }
+            for entry in entries {
+                for position in &entry.positions {
+                    if position.len() != game.board.dimensions.len()
+                        || !game.is_position_valid(&into_extended_position(position))
+                    {
+                        return Err(PuzzleError::InvalidPosition(position.clone()));
+                    }
+                    game.state.pieces.insert(
+                        position.clone(),
+                        Piece::new(entry.piece.clone(), player.clone()),
+                    );
+                }
+            }
+        }
+
+        let Some(turn) = game.turn_order.iter().position(|p| *p == spec.to_move) else {
+            return Err(PuzzleError::UnknownPlayer(spec.to_move.clone()));
+        };
+        game.state.current_turn = turn as u8;
+        game.state.hand = spec.hand.clone();
+        game.state.phase = GamePhase::Idle;
+        game.state.available_moves = None;
+        game.state.history.clear();
+        game.state.position_hashes.clear();
+        game.state.check_counts.clear();
+
+        let movers = Self::replay(&game, &spec.solution)?;
+
+        Ok(Puzzle {
+            solution: spec.solution.clone(),
+            movers,
+            cursor: 0,
+            status: PuzzleStatus::InProgress,
+            spec,
+            game,
+        })
+    }
+
+    pub fn status(&self) -> &PuzzleStatus {
+        &self.status
+    }
+
+    /// The ply the solution expects next from the user, if any.
+    pub fn hint(&self) -> Option<&Ply> {
+        self.solution.get(self.cursor)
+    }
+
+    /// Checks `ply` against the solution. Illegal plies are rejected without changing the
+    /// puzzle; a legal but wrong ply fails it. On success the opponent's replies are played.
+    pub fn submit(&mut self, ply: &Ply) -> Result<PuzzleStatus, PuzzleError> {
+        if self.status != PuzzleStatus::InProgress {
+            return Err(PuzzleError::NotInProgress);
+        }
+        if !self.game.legal_plies().contains(ply) {
+            return Err(PuzzleError::IllegalMove);
+        }
+
+        let accepted = self.solution.get(self.cursor) == Some(ply)
+            || (self.spec.goal == MATE && self.accept_alternative(ply));
+        if !accepted {
+            self.status = PuzzleStatus::Failed;
+            return Ok(self.status.clone());
+        }
+
+        self.game.play(ply).map_err(|_| PuzzleError::IllegalMove)?;
+        self.cursor += 1;
+
+        // Auto-play the opponent replies from the solution.
+        while self.cursor < self.solution.len()
+            && !self.is_over()
+            && self.game.current_player() != self.spec.to_move
+        {
+            let reply = self.solution[self.cursor].clone();
+            self.game
+                .play(&reply)
+                .map_err(|_| PuzzleError::InvalidSolution(self.cursor))?;
+            self.cursor += 1;
+        }
+
+        if self.cursor >= self.solution.len() || self.is_over() {
+            self.status = PuzzleStatus::Solved;
+        }
+        Ok(self.status.clone())
+    }
+
+    /// For "MATE" puzzles: accepts a ply that differs from the solution when it still forces
+    /// a win within the user's remaining moves, and replaces the rest of the solution with
+    /// the solver's main line.
+    fn accept_alternative(&mut self, ply: &Ply) -> bool {
+        let solver = self.spec.to_move.clone();
+        let remaining = self.movers[self.cursor..]
+            .iter()
+            .skip(1)
+            .filter(|p| **p == solver)
+            .count() as u8;
+
+        let mut sim = self.game.clone();
+        if sim.play(ply).is_err() {
+            return false;
+        }
+        let line = match &sim.state.phase {
+            GamePhase::GameOver { winner } if winner.as_deref() == Some(solver.as_str()) => {
+                Vec::new()
+            }
+            _ if remaining == 0 => return false,
+            _ => match MateSolver::new(remaining).solve_after(&sim, &solver) {
+                MateResult::Mate { line, .. } => line,
+                MateResult::NoMate => return false,
+            },
+        };
+
+        let Ok(movers) = Self::replay(&sim, &line) else {
+            return false;
+        };
+        self.solution.truncate(self.cursor);
+        self.solution.push(ply.clone());
+        self.solution.extend(line);
+        self.movers.truncate(self.cursor);
+        self.movers.push(solver);
+        self.movers.extend(movers);
+        true
+    }
+
+    fn is_over(&self) -> bool {
+        matches!(self.game.state.phase, GamePhase::GameOver { .. })
+    }
+
+    /// Plays `line` on a copy of `game`, returning who made each ply.
+    fn replay(game: &Game, line: &[Ply]) -> Result<Vec<String>, PuzzleError> {
+        let mut sim = game.clone();
+        let mut movers = Vec::with_capacity(line.len());
+        for (index, ply) in line.iter().enumerate() {
+            movers.push(sim.current_player());
+            if !sim.legal_plies().contains(ply) || sim.play(ply).is_err() {
+                return Err(PuzzleError::InvalidSolution(index));
+            }
+        }
+        Ok(movers)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::logic::{Game, GamePhase, Ply};
+
+/// Outcome of a forced-win search.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MateResult {
+    /// The attacker can force a win. `line` alternates attacker plies and the defence that
+    /// holds out longest, ending with the winning ply.
+    Mate { moves: u8, line: Vec<Ply> },
+
+    /// Every attacker try within the depth has at least one defence that avoids losing.
+    NoMate,
+}
+
+/// Proves or refutes "the side to move wins by force in at most N of its own moves".
+///
+/// A win is any position where the game ends with the attacker as winner, or where the
+/// defender — the player after the attacker in the turn order — is eliminated (checkmated in
+/// an N-player game). Both are detected by
+/// playing candidate plies through `Game::play`, which runs `check_game_over` — so
+/// checkmate (`leader_in_check` with no `any_legal_moves`), the spec's `win_conditions`
+/// (`PIECE_IN_ZONE`, `CHECK_COUNT`, ...) and drops from `state.hand` are all covered.
+pub struct MateSolver {
+    /// Maximum number of attacker moves to search.
+    pub max_moves: u8,
+}
+
+impl MateSolver {
+    pub fn new(max_moves: u8) -> Self {
+        MateSolver { max_moves }
+    }
+
+    /// Searches for the shortest forced win for `game.current_player()`, deepening one
+    /// attacker move at a time up to `max_moves`.
+    pub fn solve(&self, game: &Game) -> MateResult {
+        if matches!(game.state.phase, GamePhase::GameOver { .. }) {
+            return MateResult::NoMate;
+        }
+
+        let attacker = game.current_player();
+        for moves in 1..=self.max_moves {
+            if let Some(line) = Self::attack(game, &attacker, moves) {
+                return MateResult::Mate { moves, line };
+            }
+        }
+        MateResult::NoMate
+    }
+
+    /// Like `solve`, but for a position where `attacker` has just moved and someone else is
+    /// to play: proves that every defence loses within `max_moves` further attacker moves.
+    /// The returned line starts with the defender's reply.
+    pub fn solve_after(&self, game: &Game, attacker: &str) -> MateResult {
+        match &game.state.phase {
+            GamePhase::GameOver { winner } if winner.as_deref() == Some(attacker) => {
+                return MateResult::Mate {
+                    moves: 0,
+                    line: Vec::new(),
+                };
+            }
+            GamePhase::GameOver { .. } => return MateResult::NoMate,
+            _ => {}
+        }
+
+        for moves in 1..=self.max_moves {
+            if let Some(line) = Self::defend(game, attacker, moves) {
+                return MateResult::Mate { moves, line };
+            }
+        }
+        MateResult::NoMate
+    }
+
+    /// Attacker to move with `moves` moves left: succeeds if any ply forces a win.
+    fn attack(game: &Game, attacker: &str, moves: u8) -> Option<Vec<Ply>> {
+        for (ply, next) in Self::ordered_children(game) {
+            if Self::attacker_won(game, &next, attacker) {
+                return Some(vec![ply]);
+            }
+            if moves <= 1 || matches!(next.state.phase, GamePhase::GameOver { .. }) {
+                continue;
+            }
+            if let Some(rest) = Self::defend(&next, attacker, moves - 1) {
+                let mut line = vec![ply];
+                line.extend(rest);
+                return Some(line);
+            }
+        }
+        None
+    }
+
+    /// Some other player to move: succeeds only if *every* reply still loses. Returns the
+    /// longest resistance as the main line.
+    fn defend(game: &Game, attacker: &str, moves: u8) -> Option<Vec<Ply>> {
+        if game.current_player() == attacker {
+            return Self::attack(game, attacker, moves);
+        }
+
+        let mut main_line: Option<Vec<Ply>> = None;
+        for (ply, next) in Self::ordered_children(game) {
+            if Self::attacker_won(game, &next, attacker) {
+                // The reply itself hands the attacker the win (e.g. a self-elimination).
+                main_line.get_or_insert_with(|| vec![ply]);
+                continue;
+            }
+            if matches!(next.state.phase, GamePhase::GameOver { .. }) {
+                return None;
+            }
+
+            let rest = Self::defend(&next, attacker, moves)?;
+            if main_line
+                .as_ref()
+                .is_none_or(|line| rest.len() + 1 > line.len())
+            {
+                let mut line = vec![ply];
+                line.extend(rest);
+                main_line = Some(line);
+            }
+        }
+        main_line
+    }
+
+    /// True when playing into `after` from `before` won the game for `attacker`: either the
+    /// game ended in their favour, or the defender (the player after the attacker in the turn
+    /// order) got eliminated. Eliminating anyone else in an N-player game is not a win.
+    fn attacker_won(before: &Game, after: &Game, attacker: &str) -> bool {
+        match &after.state.phase {
+            GamePhase::GameOver { winner } => winner.as_deref() == Some(attacker),
+            _ => {
+                // N-player checkmate: `check_game_over` drops the mated player from the order.
+                let Some(defender) = Self::defender(before, attacker) else {
+                    return false;
+                };
+                !after.turn_order.iter().any(|p| p == defender)
+                    && after.turn_order.iter().any(|p| p == attacker)
+            }
+        }
+    }
+
+    /// The player after `attacker` in `game`'s turn order, if `attacker` is still playing.
+    fn defender<'a>(game: &'a Game, attacker: &str) -> Option<&'a String> {
+        let index = game.turn_order.iter().position(|p| p == attacker)?;
+        let next = (index + 1) % game.turn_order.len();
+        (next != index).then(|| &game.turn_order[next])
+    }
+
+    /// Plays every legal ply on a copy of `game`, putting checking plies first so that
+    /// forcing lines are found early.
+    fn ordered_children(game: &Game) -> Vec<(Ply, Game)> {
+        let mut children: Vec<(bool, Ply, Game)> = game
+            .legal_plies()
+            .into_iter()
+            .filter_map(|ply| {
+                let mut next = game.clone();
+                next.play(&ply).ok()?;
+                let gives_check = !matches!(next.state.phase, GamePhase::GameOver { .. })
+                    && next.leader_in_check();
+                Some((gives_check, ply, next))
+            })
+            .collect();
+        children.sort_by_key(|(gives_check, _, _)| !gives_check);
+        children
+            .into_iter()
+            .map(|(_, ply, next)| (ply, next))
+            .collect()
+    }
+}
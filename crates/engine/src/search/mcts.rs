@@ -0,0 +1,253 @@
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::logic::{Game, GamePhase, Ply};
+
+/// How much work a single `Mcts::search` call may do.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MctsBudget {
+    /// Run exactly this many select/expand/playout/backpropagate iterations.
+    Iterations(u32),
+    /// Keep iterating until this much wall-clock time has elapsed.
+    Time(Duration),
+}
+
+/// Tuning knobs for the UCT search.
+#[derive(Clone, Debug)]
+pub struct MctsConfig {
+    pub budget: MctsBudget,
+
+    /// UCT exploration constant `c` in `Q + c * sqrt(ln N / n)`. Defaults to √2.
+    pub exploration: f64,
+
+    /// Random playouts that reach this many plies without a result are scored as a draw
+    /// between the players still in the game.
+    pub max_playout_plies: u32,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        MctsConfig {
+            budget: MctsBudget::Iterations(1_000),
+            exploration: std::f64::consts::SQRT_2,
+            max_playout_plies: 200,
+        }
+    }
+}
+
+/// Visit statistics for one candidate ply at the root of the search tree.
+#[derive(Clone, Debug)]
+pub struct MctsMoveStats {
+    pub ply: Ply,
+    pub visits: u32,
+    /// Mean reward for the player making this ply, in `[0, 1]`.
+    pub mean_reward: f64,
+}
+
+/// Outcome of a search: the recommended ply plus per-move statistics.
+#[derive(Clone, Debug)]
+pub struct MctsResult {
+    /// The most visited root ply, or `None` when the game is already over or the current
+    /// player has nothing to play.
+    pub best: Option<Ply>,
+    pub iterations: u32,
+    /// Root children, most visited first.
+    pub moves: Vec<MctsMoveStats>,
+}
+
+/// Monte Carlo tree search player (UCT with uniformly random playouts).
+///
+/// The search only relies on `Game::legal_plies`, `Game::play` and the `GameOver` phase set by
+/// `check_game_over`, so it works for any spec — including drop games and N-player games.
+/// Rewards are tracked as one value per entry of `game.players`: a win scores 1 for the winner,
+/// a draw splits 1 evenly between the players still in the turn order, and eliminated players
+/// score 0. Each node is scored from the perspective of the player who moved into it.
+pub struct Mcts {
+    pub config: MctsConfig,
+}
+
+struct Node {
+    ply: Option<Ply>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: Vec<Ply>,
+    // Index into `game.players` of the player who played `ply`.
+    mover: usize,
+    visits: u32,
+    rewards: Vec<f64>,
+}
+
+impl Mcts {
+    pub fn new(config: MctsConfig) -> Self {
+        Mcts { config }
+    }
+
+    /// Searches from the current position of `game` (which is left untouched) and returns
+    /// the recommended ply. Passing the same seeded `rng` yields the same result.
+    pub fn search<R: Rng>(&self, game: &Game, rng: &mut R) -> MctsResult {
+        let player_count = game.players.len();
+        let mut nodes = vec![Node {
+            ply: None,
+            parent: None,
+            children: Vec::new(),
+            untried: Self::expandable_plies(game),
+            mover: 0,
+            visits: 0,
+            rewards: vec![0.0; player_count],
+        }];
+
+        let started = Instant::now();
+        let mut iterations = 0u32;
+
+        while !nodes[0].untried.is_empty() || !nodes[0].children.is_empty() {
+            match self.config.budget {
+                MctsBudget::Iterations(limit) if iterations >= limit => break,
+                MctsBudget::Time(limit) if started.elapsed() >= limit => break,
+                _ => {}
+            }
+
+            let mut sim = game.clone();
+            let mut node = 0usize;
+
+            // Selection: descend through fully expanded nodes.
+            while nodes[node].untried.is_empty() && !nodes[node].children.is_empty() {
+                node = self.select_child(&nodes, node);
+                let ply = nodes[node].ply.clone().expect("non-root nodes carry a ply");
+                if sim.play(&ply).is_err() {
+                    break;
+                }
+            }
+
+            // Expansion: add one untried child.
+            if !nodes[node].untried.is_empty() {
+                let index = rng.gen_range(0..nodes[node].untried.len());
+                let ply = nodes[node].untried.swap_remove(index);
+                let mover = Self::player_index(&sim, &sim.current_player());
+                if sim.play(&ply).is_ok() {
+                    let child = nodes.len();
+                    nodes.push(Node {
+                        ply: Some(ply),
+                        parent: Some(node),
+                        children: Vec::new(),
+                        untried: Self::expandable_plies(&sim),
+                        mover,
+                        visits: 0,
+                        rewards: vec![0.0; player_count],
+                    });
+                    nodes[node].children.push(child);
+                    node = child;
+                }
+            }
+
+            // Simulation and backpropagation.
+            let rewards = self.playout(&mut sim, rng);
+            let mut cursor = Some(node);
+            while let Some(current) = cursor {
+                nodes[current].visits += 1;
+                for (total, reward) in nodes[current].rewards.iter_mut().zip(&rewards) {
+                    *total += reward;
+                }
+                cursor = nodes[current].parent;
+            }
+
+            iterations += 1;
+        }
+
+        let mut moves: Vec<MctsMoveStats> = nodes[0]
+            .children
+            .iter()
+            .map(|&child| {
+                let node = &nodes[child];
+                MctsMoveStats {
+                    ply: node.ply.clone().expect("non-root nodes carry a ply"),
+                    visits: node.visits,
+                    mean_reward: node.rewards[node.mover] / node.visits.max(1) as f64,
+                }
+            })
+            .collect();
+        moves.sort_by_key(|m| std::cmp::Reverse(m.visits));
+
+        MctsResult {
+            best: moves.first().map(|m| m.ply.clone()),
+            iterations,
+            moves,
+        }
+    }
+
+    /// Picks the child of `parent` with the highest UCT score for the player to move.
+    fn select_child(&self, nodes: &[Node], parent: usize) -> usize {
+        let log_parent = (nodes[parent].visits.max(1) as f64).ln();
+        let score = |child: usize| {
+            let node = &nodes[child];
+            if node.visits == 0 {
+                return f64::INFINITY;
+            }
+            let visits = node.visits as f64;
+            node.rewards[node.mover] / visits
+                + self.config.exploration * (log_parent / visits).sqrt()
+        };
+
+        nodes[parent]
+            .children
+            .iter()
+            .copied()
+            .max_by(|&a, &b| score(a).total_cmp(&score(b)))
+            .expect("select_child requires at least one child")
+    }
+
+    /// Plays uniformly random legal plies until the game ends or the ply cap is hit, and
+    /// returns the reward vector of the final position.
+    fn playout<R: Rng>(&self, sim: &mut Game, rng: &mut R) -> Vec<f64> {
+        for _ in 0..self.config.max_playout_plies {
+            if matches!(sim.state.phase, GamePhase::GameOver { .. }) {
+                break;
+            }
+            let plies = sim.legal_plies();
+            if plies.is_empty() {
+                break;
+            }
+            let ply = &plies[rng.gen_range(0..plies.len())];
+            if sim.play(ply).is_err() {
+                break;
+            }
+        }
+        Self::rewards(sim)
+    }
+
+    /// Scores a (possibly unfinished) position for every player in `game.players`.
+    fn rewards(game: &Game) -> Vec<f64> {
+        let mut rewards = vec![0.0; game.players.len()];
+        match &game.state.phase {
+            GamePhase::GameOver {
+                winner: Some(winner),
+            } => {
+                rewards[Self::player_index(game, winner)] = 1.0;
+            }
+            _ => {
+                // Draw, or playout cut short: share the point among surviving players.
+                let survivors: Vec<&String> = game
+                    .players
+                    .iter()
+                    .filter(|p| game.turn_order.contains(p))
+                    .collect();
+                let share = 1.0 / survivors.len().max(1) as f64;
+                for player in survivors {
+                    rewards[Self::player_index(game, player)] = share;
+                }
+            }
+        }
+        rewards
+    }
+
+    fn expandable_plies(game: &Game) -> Vec<Ply> {
+        if matches!(game.state.phase, GamePhase::GameOver { .. }) {
+            return Vec::new();
+        }
+        game.legal_plies()
+    }
+
+    fn player_index(game: &Game, player: &str) -> usize {
+        game.players.iter().position(|p| p == player).unwrap_or(0)
+    }
+}
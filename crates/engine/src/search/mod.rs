@@ -0,0 +1,5 @@
+pub mod mate;
+pub mod mcts;
+
+pub use mate::{MateResult, MateSolver};
+pub use mcts::{Mcts, MctsBudget, MctsConfig, MctsMoveStats, MctsResult};
@@ -3,10 +3,14 @@ pub const EMPTY: &str = "EMPTY";
 pub const NOT_EMPTY: &str = "NOT_EMPTY";
 pub const ENEMY: &str = "ENEMY";
 pub const ALLY: &str = "ALLY";
+/// Owner of neutral pieces, which belong to no player. Also the state of a square holding one.
+pub const NEUTRAL: &str = "NEUTRAL";
 
 // Basic actions.
 pub const MOVE: &str = "MOVE";
 pub const CAPTURE: &str = "CAPTURE";
+/// Capture without moving: the target is removed and the acting piece stays on its square.
+pub const SHOOT: &str = "SHOOT";
 pub const SET_STATE: &str = "SET_STATE";
 
 // Basic conditions.
@@ -34,6 +38,9 @@ pub const TRANSFORM: &str = "TRANSFORM";
 pub const DROP: &str = "DROP";
 pub const ALLY_ON_FILE: &str = "ALLY_ON_FILE";
 
+// Placement of a neutral piece after a move (Duck Chess).
+pub const PLACE: &str = "PLACE";
+
 // Phase 17.B — new move conditions.
 /// Moving piece's source square must not be in any opponent's attack set.
 pub const SOURCE_NOT_ATTACKED: &str = "SOURCE_NOT_ATTACKED";
@@ -45,13 +52,37 @@ pub const PIECE_AT: &str = "PIECE_AT";
 pub const PIECE_NOT_AT: &str = "PIECE_NOT_AT";
 /// After simulating this move, no opponent leader may be in check.
 pub const OPPONENT_NOT_IN_CHECK: &str = "OPPONENT_NOT_IN_CHECK";
-/// Count ally pieces in the 8 surrounding squares; pass when count in [min, max].
+/// Count ally pieces on the adjacent cells; pass when count in [min, max].
 pub const ALLY_ADJACENT_COUNT: &str = "ALLY_ADJACENT_COUNT";
+/// Count the enemy pieces a `LINE_FLIP` would convert on landing; pass when count in [min, max].
+/// As a drop restriction it blocks the drop when the count is in range.
+pub const FLIP_COUNT: &str = "FLIP_COUNT";
+/// Compare a named counter on a piece against [min, max].
+pub const COUNTER: &str = "COUNTER";
+/// Compare a game variable against [min, max]. As a drop restriction it blocks the drop when the
+/// value is in range. As a win condition, a player whose variable reaches `threshold` wins.
+pub const VARIABLE: &str = "VARIABLE";
+
+// Condition combinators, over the conditions nested in `conditions`.
+/// Passes when every nested condition passes.
+pub const ALL_OF: &str = "ALL";
+/// Passes when at least one nested condition passes.
+pub const ANY_OF: &str = "ANY";
+/// Passes unless every nested condition passes (the negation of `ALL`).
+pub const NOT: &str = "NOT";
 
 // Phase 17.A — win condition type strings.
 pub const PIECE_IN_ZONE: &str = "PIECE_IN_ZONE";
 pub const OPPONENT_BARE: &str = "OPPONENT_BARE";
 pub const CHECK_COUNT: &str = "CHECK_COUNT";
+/// A player left with only exempt pieces (or none) wins.
+pub const OWN_BARE: &str = "OWN_BARE";
+/// A player with no piece left of any one of the listed codes loses.
+pub const EXTINCTION: &str = "EXTINCTION";
+/// A player who has captured `threshold` pieces (of `piece`, if set) wins.
+pub const CAPTURE_COUNT: &str = "CAPTURE_COUNT";
+/// When the game ends in stalemate, the player with the most pieces (of `piece`, if set) wins.
+pub const MAJORITY: &str = "MAJORITY";
 
 // Phase 17.C — new side-effect actions.
 /// Convert the enemy piece at a relative position into an ally piece of the specified code.
@@ -60,3 +91,58 @@ pub const CONVERT: &str = "CONVERT";
 /// Place a copy of the acting piece back at its original source square (used for clone moves
 /// where the source stone should remain in place while a new stone appears at the target).
 pub const COPY_SOURCE: &str = "COPY_SOURCE";
+/// Disable a square (relative `target` or absolute `square`) for the rest of the game, removing
+/// any piece on it. Disabled squares cannot be entered or passed through.
+pub const DISABLE: &str = "DISABLE";
+/// Enable a previously disabled square (relative `target` or absolute `square`) again.
+pub const ENABLE: &str = "ENABLE";
+/// Remove the enemies flanked by the landing piece and an ally or hostile square (tafl).
+pub const CUSTODIAL_CAPTURE: &str = "CUSTODIAL_CAPTURE";
+/// Convert every enemy run bounded by the landing piece and an ally, in every direction (Reversi).
+pub const LINE_FLIP: &str = "LINE_FLIP";
+/// Destroy the landing piece and every piece within `radius` of the landing square (Atomic).
+pub const EXPLODE: &str = "EXPLODE";
+/// Add `amount` (default 1) to a named counter.
+pub const INCREMENT: &str = "INCREMENT";
+/// Subtract `amount` (default 1) from a named counter, stopping at 0.
+pub const DECREMENT: &str = "DECREMENT";
+/// Set a named counter back to 0.
+pub const RESET: &str = "RESET";
+/// Set a game variable to `amount` (default 1).
+pub const SET_VARIABLE: &str = "SET_VARIABLE";
+/// Add `amount` (default 1) to a game variable.
+pub const INCREMENT_VARIABLE: &str = "INCREMENT_VARIABLE";
+/// Remove a game variable, so that it reads as 0 again.
+pub const CLEAR_VARIABLE: &str = "CLEAR_VARIABLE";
+
+// Counter side effect subjects.
+/// The enemy piece on the landing square.
+pub const CAPTURED: &str = "CAPTURED";
+
+// Game variable scopes.
+/// A variable of the acting player (the default).
+pub const PLAYER_SCOPE: &str = "PLAYER";
+/// A variable shared by every player.
+pub const GLOBAL_SCOPE: &str = "GLOBAL";
+
+// Custodial capture directions.
+/// Only along the board's axes (or the six hex neighbours). The default.
+pub const ORTHOGONAL: &str = "ORTHOGONAL";
+/// Along every line through a neighbouring cell, diagonals included.
+pub const ALL_DIRECTIONS: &str = "ALL";
+
+// Hands.
+/// Hand count of a piece with an unlimited supply: drops never use it up.
+pub const UNLIMITED: u32 = u32::MAX;
+
+// Board kinds.
+/// Rectangular grid of square cells (the default).
+pub const SQUARE: &str = "SQUARE";
+/// Hexagon-shaped board of hex cells, addressed with axial coordinates `[q, r]`.
+pub const HEX: &str = "HEX";
+
+// Puzzle goals.
+/// The solver must play the listed solution line exactly.
+pub const LINE: &str = "LINE";
+/// Any move that still forces a win within the remaining moves is accepted.
+pub const MATE: &str = "MATE";
@@ -6,6 +6,10 @@ use crate::{logic::Piece, shared::Position};
 pub struct BoardChange {
     pub position: Position,
     pub piece: Option<Piece>,
+
+    // `Some(false)` disables the square and `Some(true)` enables it again. A disabled square
+    // holds no piece, so either way the square is left empty.
+    pub enabled: Option<bool>,
 }
 
 impl BoardChange {
@@ -13,6 +17,7 @@ impl BoardChange {
         Self {
             position: position.clone(),
             piece: None,
+            enabled: None,
         }
     }
 
@@ -20,8 +25,48 @@ impl BoardChange {
         Self {
             position: position.clone(),
             piece: Some(piece),
+            enabled: None,
         }
     }
+
+    pub fn disable(position: &Position) -> Self {
+        Self {
+            position: position.clone(),
+            piece: None,
+            enabled: Some(false),
+        }
+    }
+
+    pub fn enable(position: &Position) -> Self {
+        Self {
+            position: position.clone(),
+            piece: None,
+            enabled: Some(true),
+        }
+    }
+}
+
+/// Lets the moved piece keep moving in the same turn, restricted to the listed move ids.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Continuation {
+    pub move_ids: Vec<u8>,
+    pub mandatory: bool,
+}
+
+/// A proposed change to a game variable (see `GameState::variables`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableChange {
+    // Player whose variable changes; `None` for a global variable.
+    pub owner: Option<String>,
+    pub name: String,
+    pub op: VariableOp,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VariableOp {
+    Set(u16),
+    Increment(u16),
+    Clear,
 }
 
 #[derive(Debug, Clone)]
@@ -36,4 +81,10 @@ pub struct Effect {
     pub action: String,
     pub board_changes: Vec<BoardChange>,
     pub metadata: Option<EffectMetadata>,
+
+    // Set when the moved piece may (or must) move again before the turn ends.
+    pub continuation: Option<Continuation>,
+
+    // Changes to game variables, applied along with the board changes.
+    pub variable_changes: Vec<VariableChange>,
 }
@@ -1,8 +1,8 @@
 pub type Position = Vec<u8>;
 pub type ExtendedPosition = Vec<i16>; // Important to calculate steps in both positive and negative directions.
 
-/// A 2×2 rotation matrix representing a player's orientation on the board.
-/// Stored in row-major order: `direction[row][col]`.
+/// An N×N orientation matrix (N being the number of board dimensions) representing a player's
+/// orientation on the board. Stored in row-major order: `direction[row][col]`.
 ///
 /// Standard orientations for 2-player chess:
 ///   WHITE (up):    `[[1, 0], [0, 1]]`   — identity
@@ -11,9 +11,72 @@ pub type ExtendedPosition = Vec<i16>; // Important to calculate steps in both po
 /// Additional orientations for 4-player chess:
 ///   Left  side (moves right): `[[0, 1], [-1, 0]]`  — 90° clockwise
 ///   Right side (moves left):  `[[0, -1], [1, 0]]`  — 90° counter-clockwise
-pub type Direction = [[i16; 2]; 2];
+///
+/// On a 3-D board, `[[1, 0, 0], [0, -1, 0], [0, 0, -1]]` turns "forward" and "up" into
+/// "backward" and "down".
+pub type Direction = Vec<Vec<i16>>;
+
+/// The N×N identity matrix: a player moving "forward" along the second axis.
+pub fn identity_direction(dimensions: usize) -> Direction {
+    (0..dimensions)
+        .map(|row| (0..dimensions).map(|col| (row == col) as i16).collect())
+        .collect()
+}
+
+/// Determinant of a square matrix, by cofactor expansion along the first row.
+/// Orientation matrices are tiny, so this is cheap enough.
+pub fn determinant(matrix: &[Vec<i16>]) -> i16 {
+    match matrix.len() {
+        0 => 1,
+        1 => matrix[0][0],
+        n => (0..n)
+            .map(|col| {
+                let minor: Vec<Vec<i16>> = matrix[1..]
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .enumerate()
+                            .filter(|(c, _)| *c != col)
+                            .map(|(_, &v)| v)
+                            .collect()
+                    })
+                    .collect();
+                let sign = if col % 2 == 0 { 1 } else { -1 };
+                sign * matrix[0][col] * determinant(&minor)
+            })
+            .sum(),
+    }
+}
+
+/// The six hex "orthogonals" in axial coordinates `[q, r]`: steps to the neighbouring cells.
+/// With files drawn vertically, `[0, 1]` is straight up and `[1, 0]` is up-right.
+pub const HEX_ORTHOGONALS: [[i16; 2]; 6] = [[0, 1], [1, 0], [1, -1], [0, -1], [-1, 0], [-1, 1]];
+
+/// The six hex "diagonals": the nearest cells along the lines between two orthogonals.
+/// `[2, -1]` is straight to the right.
+pub const HEX_DIAGONALS: [[i16; 2]; 6] = [[1, 1], [2, -1], [1, -2], [-1, -1], [-2, 1], [-1, 2]];
+
+/// Direction matrix rotating axial hex steps by `sixths` × 60° counter-clockwise.
+/// `hex_rotation(3)` is the 180° turn `[[-1, 0], [0, -1]]`.
+pub fn hex_rotation(sixths: i16) -> Direction {
+    let [mut a, mut b, mut c, mut d] = [1, 0, 0, 1];
+    for _ in 0..sixths.rem_euclid(6) {
+        // Left-multiply by the 60° rotation [[0, -1], [1, 1]].
+        [a, b, c, d] = [-c, -d, a + c, b + d];
+    }
+    vec![vec![a, b], vec![c, d]]
+}
+
+/// Whether an axial `position` lies on a hex board whose `dimensions` are `[size, size]`.
+/// The board is the hexagon of radius `n = (size - 1) / 2` centred on `[n, n]`, i.e. the
+/// cells with `n <= q + r <= 3n`.
+pub fn on_hex(position: &[i16], size: u8) -> bool {
+    let n = (size as i16 - 1) / 2;
+    let sum: i16 = position.iter().sum();
+    position.iter().all(|&c| (0..size as i16).contains(&c)) && (n..=3 * n).contains(&sum)
+}
 
-/// Applies a direction matrix to a step vector via 2D matrix multiplication.
+/// Applies a direction matrix to a step vector via matrix multiplication.
 /// `result[i] = Σ_j (direction[i][j] * step[j])`
 pub fn apply_direction(direction: &Direction, step: &ExtendedPosition) -> ExtendedPosition {
     direction
@@ -6,17 +6,56 @@ pub use super::TurnSpec;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashSet;
 
-use crate::shared::Position;
+use crate::shared::{Position, HEX, SQUARE};
+use crate::specs::{GameSpecError, Validate};
 
 /// Board spec, mostly consisting of layout specifications.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct BoardSpec {
+    /// Cell shape: "SQUARE" (default) or "HEX". Hex boards use axial coordinates `[q, r]`
+    /// and must have square, odd `dimensions`, e.g. `[11, 11]` for Glinski's 91 cells.
+    #[serde(default = "default_kind")]
+    pub kind: String,
+
     /// The base dimensions of the board. For instance, chess should have `vec![8u8, 8u8]`.
     pub dimensions: Position,
 
     /// A set of positions that are disabled in the domain specified by the dimensions.
     #[serde(default, deserialize_with = "deserialize_disabled_positions")]
     pub disabled_positions: HashSet<Position>,
+
+    /// Per-axis wrap-around, e.g. `[true, false]` for a cylinder whose files wrap, or
+    /// `[true, true]` for a torus. Empty (the default) means no axis wraps.
+    #[serde(default)]
+    pub wrap: Vec<bool>,
+}
+
+fn default_kind() -> String {
+    SQUARE.to_string()
+}
+
+impl Validate for BoardSpec {
+    type Arg1 = ();
+    type Arg2 = ();
+
+    /// Validates the board kind, its dimensions and the wrapped axes.
+    fn validate(&self, _: &(), _: &()) -> Result<(), GameSpecError> {
+        let wraps = self.wrap.iter().any(|&w| w);
+        if (!self.wrap.is_empty() && self.wrap.len() != self.dimensions.len())
+            || (wraps && self.kind != SQUARE)
+        {
+            return Err(GameSpecError::InvalidWrap(self.wrap.clone()));
+        }
+
+        match self.kind.as_str() {
+            SQUARE => Ok(()),
+            HEX => match self.dimensions.as_slice() {
+                [q, r] if q == r && q % 2 == 1 => Ok(()),
+                _ => Err(GameSpecError::InvalidHexDimensions(self.dimensions.clone())),
+            },
+            _ => Err(GameSpecError::UnknownBoardKind(self.kind.clone())),
+        }
+    }
 }
 
 /// Custom deserialization function for `disabled_positions`.
@@ -41,8 +80,10 @@ impl BoardSpec {
     /// Default board is just a chess board. Used for tests only.
     pub fn default() -> Self {
         BoardSpec {
+            kind: SQUARE.to_string(),
             dimensions: vec![8u8, 8u8],
             disabled_positions: HashSet::new(),
+            wrap: vec![],
         }
     }
 }
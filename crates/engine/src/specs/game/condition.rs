@@ -2,7 +2,9 @@ use serde::{de, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 
-use crate::shared::{into_string, Position, POSITION, STATE};
+use crate::shared::{POSITION, STATE};
+
+use super::region::RegionSpec;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ConditionSpec {
@@ -11,8 +13,13 @@ pub struct ConditionSpec {
     /// Type of the condition
     pub r#type: String,
 
+    /// For STATE: the state names that satisfy the condition, per player.
     #[serde(deserialize_with = "deserialize_check")]
     pub check: HashMap<String, HashSet<String>>,
+
+    /// For POSITION: the squares and regions that satisfy the condition, per player. They are
+    /// expanded into squares by `Game::from_spec`.
+    pub regions: HashMap<String, Vec<RegionSpec>>,
 }
 
 impl<'de> Deserialize<'de> for ConditionSpec {
@@ -30,28 +37,22 @@ impl<'de> Deserialize<'de> for ConditionSpec {
         let helper = Helper::deserialize(deserializer)?;
 
         // Now we can use the type to determine how to deserialize check
-        let check: HashMap<String, HashSet<String>> = match helper.r#type.as_str() {
+        let (check, regions) = match helper.r#type.as_str() {
             POSITION => {
-                let raw_map: HashMap<String, Vec<Position>> =
+                let regions: HashMap<String, Vec<RegionSpec>> =
                     serde_json::from_value(helper.check).map_err(de::Error::custom)?;
 
-                raw_map
-                    .into_iter()
-                    .map(|(team, positions)| {
-                        let position_strings: HashSet<String> =
-                            positions.iter().map(|pos| into_string(pos)).collect();
-                        (team, position_strings)
-                    })
-                    .collect()
+                (HashMap::new(), regions)
             }
             STATE => {
                 let raw_map: HashMap<String, Vec<String>> =
                     serde_json::from_value(helper.check).map_err(de::Error::custom)?;
 
-                raw_map
+                let check = raw_map
                     .into_iter()
                     .map(|(team, states)| (team, states.into_iter().collect()))
-                    .collect()
+                    .collect();
+                (check, HashMap::new())
             }
             _ => return Err(de::Error::custom("Unknown condition type")),
         };
@@ -60,6 +61,7 @@ impl<'de> Deserialize<'de> for ConditionSpec {
             code: helper.code,
             r#type: helper.r#type,
             check,
+            regions,
         })
     }
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::ExtendedPosition;
+
+/// The "flying general" rule of xiangqi: no move may leave two opposing leaders on the same
+/// line with no piece between them.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FacingLeadersSpec {
+    /// The lines along which leaders must not face each other, each given as a step in board
+    /// coordinates and followed both ways. Defaults to the files: one square along the second axis.
+    #[serde(default)]
+    pub lines: Vec<ExtendedPosition>,
+}
+
+impl FacingLeadersSpec {
+    /// The steps to follow from each leader on a board with `axes` dimensions: every line, in
+    /// both directions.
+    pub fn steps(&self, axes: usize) -> Vec<ExtendedPosition> {
+        let file: ExtendedPosition = (0..axes).map(|axis| (axis == 1) as i16).collect();
+        let lines = if self.lines.is_empty() {
+            vec![file]
+        } else {
+            self.lines.clone()
+        };
+
+        lines
+            .into_iter()
+            .flat_map(|line| {
+                let back = line.iter().map(|c| -c).collect();
+                [line, back]
+            })
+            .collect()
+    }
+}
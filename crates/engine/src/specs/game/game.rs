@@ -1,15 +1,24 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io;
 use thiserror::Error;
 
-use crate::shared::Direction;
+use crate::shared::{
+    Direction, ALLY_ON_FILE, ALL_DIRECTIONS, ALL_OF, ANY_OF, FLIP_COUNT, GLOBAL_SCOPE, NEUTRAL,
+    NOT, ORTHOGONAL, PLAYER_SCOPE, VARIABLE,
+};
 use crate::specs::Validate;
 
 use super::board::{BoardSpec, PlayerSpec, TurnSpec};
 use super::condition::ConditionSpec;
 use super::draw_conditions::DrawConditionsSpec;
-use super::piece::PieceSpec;
+use super::facing_leaders::FacingLeadersSpec;
+use super::mandatory_capture::MandatoryCaptureSpec;
+use super::piece::{PieceSpec, SideEffectSpec};
+use super::placement::PlacementSpec;
+use super::player::PiecePositionSpec;
+use super::region::RegionSpec;
+use super::stalemate::StalemateOutcome;
 use super::win_condition::WinConditionSpec;
 
 /// Full spec of a game, to be read from a .json file.
@@ -41,13 +50,22 @@ pub struct GameSpec {
     #[serde(default)]
     pub leader: Vec<String>,
 
+    /// Per-player overrides of `leader`, keyed by player name. A player listed here uses its own
+    /// leader codes (possibly none), so only one side of an asymmetric game can be checkmated.
+    #[serde(default)]
+    pub leaders: HashMap<String, Vec<String>>,
+
     /// Optional draw-condition rules (50-move, repetition, insufficient material).
     /// All sub-fields default to disabled, so games that omit this field are unaffected.
     #[serde(default)]
     pub draw_conditions: DrawConditionsSpec,
 
-    /// When `true`, a player with no legal moves always loses (Shogi rule).
-    /// When `false` (the default), no legal moves without check is a stalemate draw.
+    /// What happens to a player with no legal moves who is not in check. Defaults to a draw,
+    /// or to a loss when the legacy `stalemate_loses` flag is set.
+    #[serde(default)]
+    pub stalemate_outcome: Option<StalemateOutcome>,
+
+    /// Legacy alias for `"stalemate_outcome": "LOSS"` (Shogi rule).
     #[serde(default)]
     pub stalemate_loses: bool,
 
@@ -57,9 +75,28 @@ pub struct GameSpec {
     pub hand_enabled: bool,
 
     /// Win conditions checked after every move. When one fires the moving player wins
-    /// immediately. Evaluated before draw conditions and checkmate detection.
+    /// immediately. Evaluated before draw conditions and checkmate detection. Each entry may be
+    /// scoped to some of the players.
     #[serde(default)]
     pub win_conditions: Vec<WinConditionSpec>,
+
+    /// Starting positions for neutral pieces, which belong to no player: nobody can move or
+    /// capture them, and they block lines like any other piece.
+    #[serde(default)]
+    pub neutral_pieces: Vec<PiecePositionSpec>,
+
+    /// Optional compulsory placement of a neutral piece after every move (Duck Chess).
+    #[serde(default)]
+    pub placement: Option<PlacementSpec>,
+
+    /// Optional rule forcing the player to capture whenever they can (losing chess, draughts).
+    #[serde(default)]
+    pub mandatory_capture: Option<MandatoryCaptureSpec>,
+
+    /// Optional rule forbidding two opposing leaders from facing each other on an open line
+    /// (the xiangqi "flying general").
+    #[serde(default)]
+    pub facing_leaders: Option<FacingLeadersSpec>,
 }
 
 fn default_pieces() -> Vec<PieceSpec> {
@@ -90,17 +127,87 @@ pub enum GameSpecError {
     #[error("Unknown piece name in starting positions: {0}")]
     UnknownPieceInStartingPosition(String),
 
+    /// Some piece name in a starting hand is unknown.
+    #[error("Unknown piece name in starting hand: {0}")]
+    UnknownPieceInHand(String),
+
     /// Some position has dimensions different than the board.
     #[error("Position has invalid dimensions: {0:?}")]
     InvalidPositionDimensions(Vec<u8>),
 
+    /// A move step or relative offset in a piece spec has dimensions different than the board.
+    #[error("Step or offset has invalid dimensions: {0:?}")]
+    InvalidOffsetDimensions(Vec<i16>),
+
     /// The direction matrix is not a valid rotation (determinant must be ±1).
-    #[error("Direction is not a valid rotation matrix (det must be ±1): {0:?}")]
+    #[error("Direction is not a valid N×N rotation matrix (det must be ±1): {0:?}")]
     InvalidDirectionMatrix(Direction),
 
     /// A specified position has been marked as disabled on the board.
     #[error("The specified position is disabled on the board: {0:?}")]
     PositionDisabled(Vec<u8>),
+
+    /// A specified position lies outside the board.
+    #[error("The specified position is outside the board: {0:?}")]
+    PositionOutOfBounds(Vec<u8>),
+
+    /// The board kind is not one of the known kinds.
+    #[error("Unknown board kind: {0}")]
+    UnknownBoardKind(String),
+
+    /// `wrap` must have one entry per board dimension, and is only supported on square boards.
+    #[error("Board wrap must list one flag per dimension, on a square board: {0:?}")]
+    InvalidWrap(Vec<bool>),
+
+    /// Hex boards need two equal, odd dimensions.
+    #[error("Hex board dimensions must be two equal odd numbers: {0:?}")]
+    InvalidHexDimensions(Vec<u8>),
+
+    /// On a hex board, the direction matrix must map hex steps onto hex steps.
+    #[error("Direction is not a symmetry of the hex board: {0:?}")]
+    InvalidHexDirection(Direction),
+
+    /// The name is reserved for the owner of neutral pieces.
+    #[error("Player name is reserved for neutral pieces: {0}")]
+    ReservedPlayerName(String),
+
+    /// The piece to place after every move is not known.
+    #[error("Unknown piece name in placement: {0}")]
+    UnknownPlacementPiece(String),
+
+    /// A continuation lists a move id the piece does not have.
+    #[error("Unknown move id in continuation: {0}")]
+    UnknownContinuationMove(u8),
+
+    /// A per-player leader or win condition names a player that is not known.
+    #[error("Unknown player name in scoped rule: {0}")]
+    UnknownScopedPlayer(String),
+
+    /// A region in a POSITION condition does not fit the board, or names a relative rank for a
+    /// player whose forward direction is not along a single axis.
+    #[error("Region does not fit the board: {0:?}")]
+    InvalidRegion(RegionSpec),
+
+    /// A `facing_leaders` line is empty or has dimensions different than the board.
+    #[error("Invalid facing_leaders line: {0:?}")]
+    InvalidFacingLine(Vec<i16>),
+
+    /// A drop restriction or drop side-effect condition uses a code drops cannot check, such as
+    /// a move-only condition.
+    #[error("Condition is not supported on drops: {0}")]
+    UnsupportedDropCondition(String),
+
+    /// A variable condition or side effect has a scope other than PLAYER or GLOBAL.
+    #[error("Unknown variable scope: {0}")]
+    UnknownVariableScope(String),
+
+    /// A custodial capture lists `directions` other than ORTHOGONAL or ALL.
+    #[error("Unknown custodial capture directions: {0}")]
+    UnknownCustodialDirections(String),
+
+    /// `stalemate_loses` is set together with a `stalemate_outcome` other than `LOSS`.
+    #[error("stalemate_loses conflicts with stalemate_outcome: {0:?}")]
+    ConflictingStalemateOutcome(StalemateOutcome),
 }
 
 impl Validate for GameSpec {
@@ -112,9 +219,20 @@ impl Validate for GameSpec {
         let player_names: HashSet<String> = self.players.iter().map(|p| p.name.clone()).collect();
         let piece_names: HashSet<String> = self.pieces.iter().map(|p| p.code.clone()).collect();
 
+        self.board.validate(&(), &())?;
         self.validate_player_specs(&piece_names)?;
+        self.validate_neutral_pieces(&piece_names)?;
+        self.validate_piece_dimensions()?;
+        self.validate_condition_regions()?;
+        self.validate_facing_leaders()?;
+        self.validate_continuations()?;
+        self.validate_drop_conditions()?;
+        self.validate_variable_scopes()?;
+        self.validate_custodial_directions()?;
         self.validate_player_name_duplicates(&player_names)?;
         self.validate_players_in_turn_order(&player_names)?;
+        self.validate_scoped_players(&player_names)?;
+        self.validate_stalemate_outcome()?;
 
         Ok(())
     }
@@ -125,6 +243,15 @@ impl GameSpec {
         self.validate(&(), &())
     }
 
+    /// The stalemate outcome, resolving the legacy `stalemate_loses` flag.
+    pub fn stalemate(&self) -> StalemateOutcome {
+        match self.stalemate_outcome {
+            Some(outcome) => outcome,
+            None if self.stalemate_loses => StalemateOutcome::Loss,
+            None => StalemateOutcome::Draw,
+        }
+    }
+
     /// Validates whether if player names are repeated or not.
     fn validate_player_name_duplicates(
         &self,
@@ -163,12 +290,260 @@ impl GameSpec {
         Ok(())
     }
 
+    /// Validates that per-player leaders and win conditions only name known players.
+    fn validate_scoped_players(&self, player_names: &HashSet<String>) -> Result<(), GameSpecError> {
+        let scoped = self
+            .leaders
+            .keys()
+            .chain(self.win_conditions.iter().flat_map(|wc| &wc.players));
+
+        for player_name in scoped {
+            if !player_names.contains(player_name) {
+                return Err(GameSpecError::UnknownScopedPlayer(player_name.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that every move step, relative offset and absolute side-effect square in the
+    /// piece specs has one coordinate per board dimension. Offsets are checked wherever a
+    /// condition can appear: moves, actions, modifiers, `repeat.until`, side effects (drop ones
+    /// included) and drop restrictions.
+    fn validate_piece_dimensions(&self) -> Result<(), GameSpecError> {
+        let dimensions = self.board.dimensions.len();
+
+        for piece in &self.pieces {
+            let side_effects = piece_side_effects(piece);
+            let conditions = piece
+                .moves
+                .iter()
+                .flat_map(|m| {
+                    m.conditions
+                        .iter()
+                        .chain(m.actions.iter().flat_map(|a| &a.conditions))
+                        .chain(m.modifiers.iter().flat_map(|modifier| &modifier.conditions))
+                        .chain(m.repeat.iter().filter_map(|r| r.until.as_ref()))
+                })
+                .chain(&piece.drop_restrictions)
+                .chain(side_effects.clone().filter_map(|se| se.condition.as_ref()))
+                .flat_map(|c| c.flatten());
+
+            let squares = side_effects
+                .clone()
+                .filter_map(|se| se.square.as_ref())
+                .map(|square| square.iter().map(|&c| c as i16).collect::<Vec<i16>>());
+            let offsets = conditions
+                .filter_map(|c| c.position.as_ref())
+                .chain(
+                    side_effects
+                        .flat_map(|se| [&se.from, &se.to, &se.target].into_iter().flatten()),
+                )
+                .map(|offset| offset.iter().map(|&c| c as i16).collect::<Vec<i16>>())
+                .chain(squares)
+                .chain(piece.moves.iter().map(|m| m.step.clone()));
+
+            for offset in offsets {
+                if offset.len() != dimensions {
+                    return Err(GameSpecError::InvalidOffsetDimensions(offset));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that every square and region in the POSITION conditions fits the board, as seen
+    /// by the player it is listed for.
+    fn validate_condition_regions(&self) -> Result<(), GameSpecError> {
+        for (player, regions) in self.conditions.iter().flat_map(|c| &c.regions) {
+            // Regions listed for an unknown player are checked with the canonical forward step.
+            let forward = self
+                .players
+                .iter()
+                .find(|p| p.name == *player)
+                .map(|p| p.forward())
+                .unwrap_or_else(|| {
+                    (0..self.board.dimensions.len())
+                        .map(|axis| (axis == 1) as i16)
+                        .collect()
+                });
+
+            if let Some(region) = regions
+                .iter()
+                .find(|region| !region.fits(&self.board.dimensions, &forward))
+            {
+                return Err(GameSpecError::InvalidRegion(region.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that every `facing_leaders` line is a non-zero step with one coordinate per
+    /// board dimension.
+    fn validate_facing_leaders(&self) -> Result<(), GameSpecError> {
+        let Some(rule) = &self.facing_leaders else {
+            return Ok(());
+        };
+
+        for line in &rule.lines {
+            if line.len() != self.board.dimensions.len() || line.iter().all(|&c| c == 0) {
+                return Err(GameSpecError::InvalidFacingLine(line.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that continuations only list moves of the piece they belong to.
+    fn validate_continuations(&self) -> Result<(), GameSpecError> {
+        for piece in &self.pieces {
+            let continuations = piece
+                .moves
+                .iter()
+                .flat_map(|m| &m.actions)
+                .filter_map(|a| a.continuation.as_ref());
+
+            for continuation in continuations {
+                if let Some(id) = continuation
+                    .moves
+                    .iter()
+                    .find(|id| !piece.moves.iter().any(|m| m.id == **id))
+                {
+                    return Err(GameSpecError::UnknownContinuationMove(*id));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that drop restrictions and drop side-effect conditions, nested ones included,
+    /// only use codes drops can check: the drop conditions and the game's named conditions.
+    fn validate_drop_conditions(&self) -> Result<(), GameSpecError> {
+        let drop_conditions = self.pieces.iter().flat_map(|piece| {
+            piece.drop_restrictions.iter().chain(
+                piece
+                    .drop_side_effects
+                    .iter()
+                    .filter_map(|se| se.condition.as_ref()),
+            )
+        });
+
+        for condition in drop_conditions.flat_map(|c| c.flatten()) {
+            let code = condition.condition.as_str();
+            let supported = [ALLY_ON_FILE, FLIP_COUNT, VARIABLE, ALL_OF, ANY_OF, NOT]
+                .contains(&code)
+                || self.conditions.iter().any(|c| c.code == code);
+            if !supported {
+                return Err(GameSpecError::UnsupportedDropCondition(code.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that every condition and side effect in the piece specs, nested conditions
+    /// included, scopes its variable to PLAYER or GLOBAL.
+    fn validate_variable_scopes(&self) -> Result<(), GameSpecError> {
+        for piece in &self.pieces {
+            let side_effects = piece_side_effects(piece);
+            let conditions = piece
+                .moves
+                .iter()
+                .flat_map(|m| {
+                    m.conditions
+                        .iter()
+                        .chain(m.actions.iter().flat_map(|a| &a.conditions))
+                        .chain(m.modifiers.iter().flat_map(|md| &md.conditions))
+                        .chain(m.repeat.iter().filter_map(|r| r.until.as_ref()))
+                })
+                .chain(&piece.drop_restrictions)
+                .chain(side_effects.clone().filter_map(|se| se.condition.as_ref()))
+                .flat_map(|c| c.flatten());
+
+            let scopes = conditions
+                .filter_map(|c| c.scope.as_ref())
+                .chain(side_effects.filter_map(|se| se.scope.as_ref()));
+            for scope in scopes {
+                if scope != PLAYER_SCOPE && scope != GLOBAL_SCOPE {
+                    return Err(GameSpecError::UnknownVariableScope(scope.clone()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that custodial captures flank along ORTHOGONAL or ALL directions.
+    fn validate_custodial_directions(&self) -> Result<(), GameSpecError> {
+        let directions = self
+            .pieces
+            .iter()
+            .flat_map(piece_side_effects)
+            .filter_map(|se| se.directions.as_ref());
+
+        for directions in directions {
+            if directions != ORTHOGONAL && directions != ALL_DIRECTIONS {
+                return Err(GameSpecError::UnknownCustodialDirections(
+                    directions.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that the legacy `stalemate_loses` flag agrees with `stalemate_outcome`.
+    fn validate_stalemate_outcome(&self) -> Result<(), GameSpecError> {
+        match self.stalemate_outcome {
+            Some(outcome) if self.stalemate_loses && outcome != StalemateOutcome::Loss => {
+                Err(GameSpecError::ConflictingStalemateOutcome(outcome))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Validates players specs to be valid.
     fn validate_player_specs(&self, piece_names: &HashSet<String>) -> Result<(), GameSpecError> {
         for player in &self.players {
+            if player.name == NEUTRAL {
+                return Err(GameSpecError::ReservedPlayerName(player.name.clone()));
+            }
             player.validate(&piece_names, &self.board)?;
         }
 
         Ok(())
     }
+
+    /// Validates the neutral starting positions and the post-move placement piece.
+    fn validate_neutral_pieces(&self, piece_names: &HashSet<String>) -> Result<(), GameSpecError> {
+        for positions_spec in &self.neutral_pieces {
+            positions_spec.validate(piece_names, &self.board)?;
+        }
+
+        if let Some(placement) = &self.placement {
+            if !piece_names.contains(&placement.piece) {
+                return Err(GameSpecError::UnknownPlacementPiece(
+                    placement.piece.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Every side effect of `piece`: those of its moves and their actions, then its drop side effects.
+fn piece_side_effects(piece: &PieceSpec) -> impl Iterator<Item = &SideEffectSpec> + Clone {
+    piece
+        .moves
+        .iter()
+        .flat_map(|m| {
+            m.side_effects
+                .iter()
+                .chain(m.actions.iter().flat_map(|a| &a.side_effects))
+        })
+        .chain(&piece.drop_side_effects)
 }
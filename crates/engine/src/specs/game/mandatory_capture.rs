@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// The "must capture" rule of losing chess and draughts: whenever the player to move has any
+/// capture, quiet moves and drops are illegal.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MandatoryCaptureSpec {
+    /// When `true`, only the captures that take the most pieces are legal, counting every leg
+    /// of a multi-leg move (the draughts majority rule).
+    #[serde(default)]
+    pub maximum: bool,
+}
@@ -1,8 +1,13 @@
 pub mod board;
 pub mod condition;
 pub mod draw_conditions;
+pub mod facing_leaders;
+pub mod mandatory_capture;
 pub mod piece;
+pub mod placement;
 pub mod player;
+pub mod region;
+pub mod stalemate;
 pub mod turns;
 pub mod win_condition;
 
@@ -11,9 +16,14 @@ pub mod parser;
 
 pub use board::BoardSpec;
 pub use draw_conditions::DrawConditionsSpec;
+pub use facing_leaders::FacingLeadersSpec;
 pub use game::{GameSpec, GameSpecError};
-pub use piece::{ActionSpec, ConditionSpec, MoveSpec, PieceSpec};
-pub use player::PlayerSpec;
+pub use mandatory_capture::MandatoryCaptureSpec;
+pub use piece::{ActionSpec, ConditionSpec, MoveSpec, PieceSpec, SideEffectSpec};
+pub use placement::PlacementSpec;
+pub use player::{HandSpec, PiecePositionSpec, PlayerSpec};
+pub use region::RegionSpec;
+pub use stalemate::StalemateOutcome;
 pub use turns::TurnSpec;
 pub use win_condition::WinConditionSpec;
 
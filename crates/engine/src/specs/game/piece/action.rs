@@ -1,4 +1,5 @@
 use super::condition::ConditionSpec;
+use super::continuation::ContinuationSpec;
 use super::side_effect::SideEffectSpec;
 use serde::{Deserialize, Serialize};
 
@@ -13,4 +14,7 @@ pub struct ActionSpec {
 
     #[serde(default)]
     pub side_effects: Vec<SideEffectSpec>,
+
+    #[serde(default)]
+    pub continuation: Option<ContinuationSpec>,
 }
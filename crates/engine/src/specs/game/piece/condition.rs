@@ -10,19 +10,59 @@ pub struct ConditionSpec {
     #[serde(default)]
     pub state: Option<String>,
 
+    /// Relative offset from the source, with one coordinate per board dimension.
     #[serde(default)]
-    pub position: Option<[i8; 2]>,
+    pub position: Option<Vec<i8>>,
 
     /// For ALLY_ON_FILE, PIECE_AT, PIECE_NOT_AT, ALLY_ADJACENT_COUNT:
     /// the piece code to check for (e.g. "CANNON").
     #[serde(default)]
     pub piece: Option<String>,
 
-    /// For PATH_PIECE_COUNT and ALLY_ADJACENT_COUNT: minimum count (inclusive, default 0).
+    /// For PATH_PIECE_COUNT, ALLY_ADJACENT_COUNT, FLIP_COUNT, COUNTER and VARIABLE: minimum
+    /// count (inclusive, default 0).
     #[serde(default)]
     pub min: Option<u8>,
 
-    /// For PATH_PIECE_COUNT and ALLY_ADJACENT_COUNT: maximum count (inclusive, default u8::MAX).
+    /// For PATH_PIECE_COUNT, ALLY_ADJACENT_COUNT, FLIP_COUNT, COUNTER and VARIABLE: maximum
+    /// count (inclusive, default u8::MAX; unbounded for COUNTER and VARIABLE).
     #[serde(default)]
     pub max: Option<u8>,
+
+    /// For VARIABLE: the game variable to compare against [min, max].
+    #[serde(default)]
+    pub variable: Option<String>,
+
+    /// For VARIABLE: "PLAYER" (default) for the moving player's variable or "GLOBAL".
+    #[serde(default)]
+    pub scope: Option<String>,
+
+    /// For ALL, ANY and NOT: the nested conditions to combine.
+    #[serde(default)]
+    pub conditions: Vec<ConditionSpec>,
+}
+
+impl ConditionSpec {
+    /// A condition given by its code alone, e.g. a named global condition.
+    pub fn named(code: impl Into<String>) -> Self {
+        ConditionSpec {
+            condition: code.into(),
+            move_id: None,
+            state: None,
+            position: None,
+            piece: None,
+            min: None,
+            max: None,
+            variable: None,
+            scope: None,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// This condition followed by every condition nested inside it, depth first.
+    pub fn flatten(&self) -> Vec<&ConditionSpec> {
+        std::iter::once(self)
+            .chain(self.conditions.iter().flat_map(|c| c.flatten()))
+            .collect()
+    }
 }
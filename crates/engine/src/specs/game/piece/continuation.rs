@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Lets the moved piece keep moving in the same turn (e.g. draughts capture chains). After the
+/// action fires, the piece may only use the listed moves until the chain ends.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ContinuationSpec {
+    /// Ids of the piece's moves that may continue the chain.
+    pub moves: Vec<u8>,
+
+    /// When `true` the chain must go on while any listed move is legal; otherwise the player
+    /// may stop after any leg.
+    #[serde(default)]
+    pub mandatory: bool,
+}
@@ -1,5 +1,6 @@
 pub mod action;
 pub mod condition;
+pub mod continuation;
 pub mod modifier;
 pub mod r#move;
 pub mod piece;
@@ -7,5 +8,7 @@ pub mod side_effect;
 
 pub use action::ActionSpec;
 pub use condition::ConditionSpec;
+pub use continuation::ContinuationSpec;
 pub use piece::PieceSpec;
 pub use r#move::MoveSpec;
+pub use side_effect::SideEffectSpec;
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::shared::ExtendedPosition;
 
@@ -24,12 +24,20 @@ pub struct MoveSpec {
 
     #[serde(default)]
     pub repeat: Option<RepeatSpec>,
+
+    /// Whether the move counts towards the attack map (check detection, `NOT_ATTACKED`, ...).
+    /// Defaults to `true` when the move has an `ENEMY` action or a `CAPTURE`/`CONVERT` side
+    /// effect, and `false` otherwise.
+    #[serde(default)]
+    pub attacks: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepeatSpec {
-    #[serde(default)]
-    pub until: Option<String>,
+    /// Where the repetition stops: a condition code (e.g. "NOT_EMPTY") or a full condition,
+    /// which may combine several with ALL / ANY / NOT.
+    #[serde(default, deserialize_with = "deserialize_until")]
+    pub until: Option<ConditionSpec>,
 
     #[serde(default)]
     pub times: Option<u8>,
@@ -38,3 +46,23 @@ pub struct RepeatSpec {
     #[serde(rename = "loop")]
     pub loop_move: bool,
 }
+
+/// Accepts `until` either as a bare condition code or as a condition object.
+fn deserialize_until<'de, D>(deserializer: D) -> Result<Option<ConditionSpec>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Until {
+        Code(String),
+        Condition(ConditionSpec),
+    }
+
+    Ok(
+        Option::<Until>::deserialize(deserializer)?.map(|until| match until {
+            Until::Code(code) => ConditionSpec::named(code),
+            Until::Condition(condition) => condition,
+        }),
+    )
+}
@@ -1,5 +1,6 @@
 use super::condition::ConditionSpec;
 use super::r#move::MoveSpec;
+use super::side_effect::SideEffectSpec;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -19,4 +20,9 @@ pub struct PieceSpec {
     /// can be dropped on any empty square.
     #[serde(default)]
     pub drop_restrictions: Vec<ConditionSpec>,
+
+    /// Side effects applied whenever this piece is dropped, relative to the drop square
+    /// (e.g. Reversi's `LINE_FLIP`).
+    #[serde(default)]
+    pub drop_side_effects: Vec<SideEffectSpec>,
 }
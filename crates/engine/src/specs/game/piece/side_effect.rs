@@ -21,11 +21,53 @@ pub struct SideEffectSpec {
     pub piece: Option<String>,
 
     #[serde(default)]
-    pub from: Option<[i8; 2]>,
+    pub from: Option<Vec<i8>>,
 
     #[serde(default)]
-    pub to: Option<[i8; 2]>,
+    pub to: Option<Vec<i8>>,
 
     #[serde(default)]
-    pub target: Option<[i8; 2]>,
+    pub target: Option<Vec<i8>>,
+
+    #[serde(default)]
+    pub square: Option<Vec<u8>>,
+
+    /// For CUSTODIAL_CAPTURE: "ORTHOGONAL" (default) or "ALL".
+    #[serde(default)]
+    pub directions: Option<String>,
+
+    /// For CUSTODIAL_CAPTURE: names of POSITION conditions whose squares flank like an ally.
+    #[serde(default)]
+    pub hostile: Vec<String>,
+
+    /// For CUSTODIAL_CAPTURE / EXPLODE: piece codes that are never captured.
+    #[serde(default)]
+    pub immune: Vec<String>,
+
+    /// For CUSTODIAL_CAPTURE: piece codes only captured when enclosed on every side.
+    #[serde(default)]
+    pub enclosed: Vec<String>,
+
+    /// For EXPLODE: how many steps from the landing square the blast reaches. Defaults to 1.
+    #[serde(default)]
+    pub radius: Option<u8>,
+
+    /// For INCREMENT / DECREMENT: how much the counter changes. For SET_VARIABLE: the new value.
+    /// For INCREMENT_VARIABLE: how much the variable grows. Defaults to 1.
+    #[serde(default)]
+    pub amount: Option<u16>,
+
+    /// For INCREMENT / DECREMENT / RESET: "CAPTURED" to change the piece on the landing square
+    /// instead of the moving piece (or the piece at `target`).
+    #[serde(default)]
+    pub subject: Option<String>,
+
+    /// For SET_VARIABLE / INCREMENT_VARIABLE / CLEAR_VARIABLE: the game variable to change.
+    #[serde(default)]
+    pub variable: Option<String>,
+
+    /// For SET_VARIABLE / INCREMENT_VARIABLE / CLEAR_VARIABLE: "PLAYER" (default) for the acting
+    /// player's variable or "GLOBAL" for the one shared by every player.
+    #[serde(default)]
+    pub scope: Option<String>,
 }
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// A compulsory placement that follows every move: after moving, the same player must put the
+/// neutral piece `piece` on an empty square (e.g. the duck in Duck Chess).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PlacementSpec {
+    /// Code of the neutral piece to place. If it is already on the board it is relocated, so it
+    /// has to move to a different square.
+    pub piece: String,
+}
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
-use crate::shared::{Direction, Position};
+use crate::shared::{
+    apply_direction, determinant, into_extended_position, on_hex, Direction, ExtendedPosition,
+    Position, HEX, HEX_ORTHOGONALS,
+};
 use crate::specs::{GameSpecError, Validate};
 
 use super::BoardSpec;
@@ -13,13 +16,18 @@ pub struct PlayerSpec {
     /// Player name, which doubles up as a unique identifier.
     pub name: String,
 
-    /// A 2×2 rotation matrix representing this player's orientation on the board.
+    /// An N×N rotation matrix, N being the number of board dimensions, representing this
+    /// player's orientation on the board.
     /// All canonical move steps in piece specs are transformed by this matrix at
     /// blueprint-build time, so a single piece spec works correctly for every player.
     pub direction: Direction,
 
     /// Starting positions for all pieces for this player.
     pub starting_positions: Vec<PiecePositionSpec>,
+
+    /// Pieces this player starts with in hand, ready to be dropped when `hand_enabled`.
+    #[serde(default)]
+    pub hand: Vec<HandSpec>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -32,14 +40,36 @@ pub struct PiecePositionSpec {
     pub positions: Vec<Position>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HandSpec {
+    /// Piece name, which identifies a Piece, whose spec should be loaded.
+    pub piece: String,
+
+    /// How many of the piece are in hand. `None` means an unlimited supply (Reversi stones).
+    #[serde(default)]
+    pub count: Option<u32>,
+}
+
+impl PlayerSpec {
+    /// The player's forward direction: the canonical forward step, one square along the second
+    /// axis, turned by `direction`.
+    pub fn forward(&self) -> ExtendedPosition {
+        let canonical: ExtendedPosition = (0..self.direction.len())
+            .map(|axis| (axis == 1) as i16)
+            .collect();
+        apply_direction(&self.direction, &canonical)
+    }
+}
+
 #[cfg(test)]
 impl PlayerSpec {
     /// Creates an empty player, with just a name. Used only for tests.
     pub fn from_name(name: &str) -> PlayerSpec {
         PlayerSpec {
             name: name.to_string(),
-            direction: [[1i16, 0i16], [0i16, 1i16]], // identity — moves "up"
+            direction: crate::shared::identity_direction(2), // identity — moves "up"
             starting_positions: vec![],
+            hand: vec![],
         }
     }
 }
@@ -57,34 +87,94 @@ impl Validate for PlayerSpec {
         piece_names: &HashSet<String>,
         board: &BoardSpec,
     ) -> Result<(), GameSpecError> {
-        // A valid direction matrix must have determinant ±1 (rotation or rotation+reflection).
-        // det([[a, b], [c, d]]) = a*d - b*c
-        let [[a, b], [c, d]] = self.direction;
-        let det = a * d - b * c;
+        // A valid direction matrix must be N×N for an N-dimensional board, and have
+        // determinant ±1 (rotation or rotation+reflection).
+        let dimensions = board.dimensions.len();
+        if self.direction.len() != dimensions
+            || self.direction.iter().any(|row| row.len() != dimensions)
+        {
+            return Err(GameSpecError::InvalidDirectionMatrix(
+                self.direction.clone(),
+            ));
+        }
+        let det = determinant(&self.direction);
         if det != 1 && det != -1 {
-            return Err(GameSpecError::InvalidDirectionMatrix(self.direction));
+            return Err(GameSpecError::InvalidDirectionMatrix(
+                self.direction.clone(),
+            ));
+        }
+
+        // On hex boards the matrix must also be one of the hexagon's symmetries, which is the
+        // case exactly when it permutes the six neighbouring steps.
+        if board.kind == HEX {
+            let is_symmetry = HEX_ORTHOGONALS.iter().all(|step| {
+                let mapped = apply_direction(&self.direction, &step.to_vec());
+                HEX_ORTHOGONALS
+                    .iter()
+                    .any(|s| s.as_slice() == mapped.as_slice())
+            });
+            if !is_symmetry {
+                return Err(GameSpecError::InvalidHexDirection(self.direction.clone()));
+            }
         }
 
         // Check starting positions.
         for positions_spec in &self.starting_positions {
-            // Check that the pieces in the positions are valid.
-            if !piece_names.contains(&positions_spec.piece) {
-                return Err(GameSpecError::UnknownPieceInStartingPosition(
-                    positions_spec.piece.clone(),
-                ));
+            positions_spec.validate(piece_names, board)?;
+        }
+
+        // Check the starting hand.
+        if let Some(hand_spec) = self.hand.iter().find(|h| !piece_names.contains(&h.piece)) {
+            return Err(GameSpecError::UnknownPieceInHand(hand_spec.piece.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for PiecePositionSpec {
+    // Known pieces HashSet
+    type Arg1 = HashSet<String>;
+
+    // Board dimensions
+    type Arg2 = BoardSpec;
+
+    /// Validates that the piece is known and that every position lies on the board.
+    fn validate(
+        &self,
+        piece_names: &HashSet<String>,
+        board: &BoardSpec,
+    ) -> Result<(), GameSpecError> {
+        // Check that the pieces in the positions are valid.
+        if !piece_names.contains(&self.piece) {
+            return Err(GameSpecError::UnknownPieceInStartingPosition(
+                self.piece.clone(),
+            ));
+        }
+
+        // Check that the positions themselves are valid on the board.
+        for position in &self.positions {
+            // Check for correct dimensions.
+            if position.len() != board.dimensions.len() {
+                return Err(GameSpecError::InvalidPositionDimensions(position.clone()));
+            }
+
+            // Check that the position is on the board.
+            let in_bounds = position
+                .iter()
+                .zip(board.dimensions.iter())
+                .all(|(coordinate, size)| coordinate < size);
+            let on_board = match board.kind.as_str() {
+                HEX => on_hex(&into_extended_position(position), board.dimensions[0]),
+                _ => in_bounds,
+            };
+            if !on_board {
+                return Err(GameSpecError::PositionOutOfBounds(position.clone()));
             }
 
-            // Check that the positions themselves are valid on the board.
-            for position in &positions_spec.positions {
-                // Check for correct dimensions.
-                if position.len() != board.dimensions.len() {
-                    return Err(GameSpecError::InvalidPositionDimensions(position.clone()));
-                }
-
-                // Check that position is not disabled.
-                if board.disabled_positions.contains(position) {
-                    return Err(GameSpecError::PositionDisabled(position.clone()));
-                }
+            // Check that position is not disabled.
+            if board.disabled_positions.contains(position) {
+                return Err(GameSpecError::PositionDisabled(position.clone()));
             }
         }
 
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::shared::{ExtendedPosition, Position};
+
+/// One entry of a `POSITION` condition's `check` list: a single square or a region expression.
+/// Regions are expanded into squares for each player by `Game::from_spec`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum RegionSpec {
+    /// A single square, e.g. `[4, 0]`.
+    Square(Position),
+
+    /// Every square whose second coordinate is `rank`.
+    Rank { rank: u8 },
+
+    /// Every square whose first coordinate is `file`.
+    File { file: u8 },
+
+    /// Every square between two opposite corners, both included.
+    Rectangle { from: Position, to: Position },
+
+    /// A rank counted from the player's own side, following their `direction`: 0 is the rank
+    /// they move away from, 1 the next one, and so on.
+    RelativeRank { relative_rank: u8 },
+
+    /// Every square in any of the regions.
+    Union { union: Vec<RegionSpec> },
+
+    /// Every square in the first region that is in none of the others.
+    Difference { difference: Vec<RegionSpec> },
+}
+
+impl RegionSpec {
+    /// Expands the region into squares, for a player facing `forward` on a board of
+    /// `dimensions` whose squares are `cells`.
+    pub fn squares(
+        &self,
+        cells: &[Position],
+        dimensions: &[u8],
+        forward: &ExtendedPosition,
+    ) -> HashSet<Position> {
+        let on_axis = |axis: usize, value: u8| -> HashSet<Position> {
+            cells
+                .iter()
+                .filter(|cell| cell.get(axis) == Some(&value))
+                .cloned()
+                .collect()
+        };
+
+        match self {
+            RegionSpec::Square(square) => HashSet::from([square.clone()]),
+            RegionSpec::Rank { rank } => on_axis(1, *rank),
+            RegionSpec::File { file } => on_axis(0, *file),
+            RegionSpec::Rectangle { from, to } => cells
+                .iter()
+                .filter(|cell| {
+                    cell.iter().enumerate().all(|(axis, &c)| {
+                        from.get(axis)
+                            .zip(to.get(axis))
+                            .is_some_and(|(&a, &b)| a.min(b) <= c && c <= a.max(b))
+                    })
+                })
+                .cloned()
+                .collect(),
+            RegionSpec::RelativeRank { relative_rank } => {
+                match relative_coordinate(*relative_rank, dimensions, forward) {
+                    Some((axis, value)) => on_axis(axis, value),
+                    None => HashSet::new(),
+                }
+            }
+            RegionSpec::Union { union } => union
+                .iter()
+                .flat_map(|region| region.squares(cells, dimensions, forward))
+                .collect(),
+            RegionSpec::Difference { difference } => {
+                let Some((first, rest)) = difference.split_first() else {
+                    return HashSet::new();
+                };
+                let mut squares = first.squares(cells, dimensions, forward);
+                for region in rest {
+                    for square in region.squares(cells, dimensions, forward) {
+                        squares.remove(&square);
+                    }
+                }
+                squares
+            }
+        }
+    }
+
+    /// Whether the region lies on a board of `dimensions`, for a player facing `forward`.
+    pub fn fits(&self, dimensions: &[u8], forward: &ExtendedPosition) -> bool {
+        let on_board = |square: &Position| {
+            square.len() == dimensions.len()
+                && square.iter().zip(dimensions).all(|(&c, &size)| c < size)
+        };
+
+        match self {
+            RegionSpec::Square(square) => on_board(square),
+            RegionSpec::Rank { rank } => dimensions.get(1).is_some_and(|&size| *rank < size),
+            RegionSpec::File { file } => dimensions.first().is_some_and(|&size| *file < size),
+            RegionSpec::Rectangle { from, to } => on_board(from) && on_board(to),
+            RegionSpec::RelativeRank { relative_rank } => {
+                relative_coordinate(*relative_rank, dimensions, forward).is_some()
+            }
+            RegionSpec::Union { union } => {
+                union.iter().all(|region| region.fits(dimensions, forward))
+            }
+            RegionSpec::Difference { difference } => {
+                !difference.is_empty()
+                    && difference
+                        .iter()
+                        .all(|region| region.fits(dimensions, forward))
+            }
+        }
+    }
+}
+
+/// The axis and coordinate of the `relative_rank`-th rank seen from a player facing `forward`.
+/// `None` when `forward` does not run along a single axis or the rank is off the board.
+fn relative_coordinate(
+    relative_rank: u8,
+    dimensions: &[u8],
+    forward: &ExtendedPosition,
+) -> Option<(usize, u8)> {
+    let mut axes = forward.iter().enumerate().filter(|(_, &step)| step != 0);
+    let (axis, &step) = axes.next()?;
+    if axes.next().is_some() {
+        return None;
+    }
+
+    let size = *dimensions.get(axis)?;
+    if relative_rank >= size {
+        return None;
+    }
+    let value = if step > 0 {
+        relative_rank
+    } else {
+        size - 1 - relative_rank
+    };
+    Some((axis, value))
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// What happens to a player who has no legal moves while not in check.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum StalemateOutcome {
+    /// Chess: with two players left the game is drawn. With more, the stalemated player passes
+    /// and keeps their turn slot; the game is drawn once nobody can move.
+    #[default]
+    Draw,
+
+    /// Shogi, shatranj: the stalemated player is eliminated like a checkmated one.
+    Loss,
+
+    /// Antichess: the stalemated player wins the game outright.
+    Win,
+
+    /// Reversi, Go: the stalemated player passes, even with two players. The game only ends
+    /// once nobody can move.
+    Pass,
+}
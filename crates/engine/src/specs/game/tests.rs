@@ -1,10 +1,19 @@
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use super::super::board::BoardSpec;
+    use super::super::condition::ConditionSpec;
     use super::super::draw_conditions::DrawConditionsSpec;
+    use super::super::facing_leaders::FacingLeadersSpec;
     use super::super::game::{GameSpec, GameSpecError};
-    use super::super::player::PlayerSpec;
+    use super::super::placement::PlacementSpec;
+    use super::super::player::{HandSpec, PiecePositionSpec, PlayerSpec};
+    use super::super::region::RegionSpec;
+    use super::super::stalemate::StalemateOutcome;
     use super::super::turns::TurnSpec;
+    use super::super::win_condition::WinConditionSpec;
+    use crate::shared::{hex_rotation, identity_direction, HEX, NEUTRAL};
 
     // Define constants we'll use throughout the tests.
     const PLAYER_1_NAME: &str = "Player1";
@@ -24,10 +33,16 @@ mod tests {
             turns: TurnSpec::from_order(vec![PLAYER_1_NAME, PLAYER_2_NAME]),
             conditions: vec![],
             leader: vec![],
+            leaders: HashMap::new(),
             draw_conditions: DrawConditionsSpec::default(),
+            stalemate_outcome: None,
             stalemate_loses: false,
             hand_enabled: false,
             win_conditions: vec![],
+            neutral_pieces: vec![],
+            placement: None,
+            mandatory_capture: None,
+            facing_leaders: None,
         }
     }
 
@@ -61,6 +76,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unknown_scoped_player() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec
+            .leaders
+            .insert(PLAYER_1_NAME.to_string(), vec!["KING".to_string()]);
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec
+            .leaders
+            .insert(UNKNOWN_PLAYER_NAME.to_string(), vec![]);
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownScopedPlayer(name)) => assert_eq!(name, UNKNOWN_PLAYER_NAME),
+            _ => panic!("Expected `UnknownScopedPlayer` error"),
+        }
+
+        game_spec.leaders.clear();
+        game_spec.win_conditions = vec![serde_json::from_str::<WinConditionSpec>(&format!(
+            r#"{{ "type": "OWN_BARE", "players": ["{PLAYER_2_NAME}", "{UNKNOWN_PLAYER_NAME}"] }}"#
+        ))
+        .unwrap()];
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownScopedPlayer(name)) => assert_eq!(name, UNKNOWN_PLAYER_NAME),
+            _ => panic!("Expected `UnknownScopedPlayer` error"),
+        }
+    }
+
     #[test]
     fn test_valid_turn_order() {
         let mut game_spec = create_valid_game_spec();
@@ -83,4 +125,414 @@ mod tests {
         game_spec.turns.order = vec![PLAYER_1_NAME.to_string()];
         assert!(game_spec.validate_specs().is_ok());
     }
+
+    #[test]
+    fn test_unknown_board_kind() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.board.kind = "TRIANGLE".to_string();
+
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownBoardKind(kind)) => assert_eq!(kind, "TRIANGLE"),
+            _ => panic!("Expected `UnknownBoardKind` error"),
+        }
+    }
+
+    #[test]
+    fn test_hex_board_dimensions() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.board.kind = HEX.to_string();
+
+        // The default 8x8 board has no centre cell.
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidHexDimensions(dimensions)) => {
+                assert_eq!(dimensions, vec![8, 8])
+            }
+            _ => panic!("Expected `InvalidHexDimensions` error"),
+        }
+
+        game_spec.board.dimensions = vec![11, 11];
+        assert!(game_spec.validate_specs().is_ok());
+    }
+
+    #[test]
+    fn test_hex_board_rejects_square_rotation() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.board.kind = HEX.to_string();
+        game_spec.board.dimensions = vec![11, 11];
+        game_spec.players[1].direction = vec![vec![0, -1], vec![1, 0]];
+
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidHexDirection(direction)) => {
+                assert_eq!(direction, vec![vec![0, -1], vec![1, 0]])
+            }
+            _ => panic!("Expected `InvalidHexDirection` error"),
+        }
+
+        game_spec.players[1].direction = hex_rotation(3);
+        assert!(game_spec.validate_specs().is_ok());
+    }
+
+    #[test]
+    fn test_direction_must_match_board_dimensions() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.board.dimensions = vec![5, 5, 5];
+
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidDirectionMatrix(direction)) => {
+                assert_eq!(direction, identity_direction(2))
+            }
+            _ => panic!("Expected `InvalidDirectionMatrix` error"),
+        }
+
+        for player in &mut game_spec.players {
+            player.direction = vec![vec![1, 0, 0], vec![0, -1, 0], vec![0, 0, -1]];
+        }
+        assert!(game_spec.validate_specs().is_ok());
+    }
+
+    #[test]
+    fn test_step_must_match_board_dimensions() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.pieces = vec![serde_json::from_str(
+            r#"{ "code": "ROOK", "name": "rook", "moves": [
+                { "id": 0, "step": [0, 1, 0], "actions": [{ "state": "EMPTY", "action": "MOVE" }] }
+            ] }"#,
+        )
+        .unwrap()];
+
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidOffsetDimensions(step)) => assert_eq!(step, vec![0, 1, 0]),
+            _ => panic!("Expected `InvalidOffsetDimensions` error"),
+        }
+    }
+
+    #[test]
+    fn test_nested_condition_offsets_must_match_board_dimensions() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.pieces = vec![serde_json::from_str(
+            r#"{ "code": "ROOK", "name": "rook", "moves": [
+                { "id": 0, "step": [0, 1], "actions": [{ "state": "EMPTY", "action": "MOVE" }],
+                  "conditions": [{ "condition": "NOT", "conditions": [
+                      { "condition": "PIECE_AT", "position": [1], "piece": "PAWN" }
+                  ] }] }
+            ] }"#,
+        )
+        .unwrap()];
+
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidOffsetDimensions(offset)) => assert_eq!(offset, vec![1]),
+            _ => panic!("Expected `InvalidOffsetDimensions` error"),
+        }
+    }
+
+    #[test]
+    fn test_modifier_repeat_and_drop_offsets_must_match_board_dimensions() {
+        let pieces = [
+            r#"{ "code": "ROOK", "name": "rook", "moves": [
+                { "id": 0, "step": [0, 1], "actions": [{ "state": "EMPTY", "action": "MOVE" }],
+                  "modifiers": [{ "action": "TRANSFORM", "options": ["ROOK"], "conditions": [
+                      { "condition": "PIECE_AT", "position": [1], "piece": "PAWN" }
+                  ] }] }
+            ] }"#,
+            r#"{ "code": "ROOK", "name": "rook", "moves": [
+                { "id": 0, "step": [0, 1], "actions": [{ "state": "EMPTY", "action": "MOVE" }],
+                  "repeat": { "loop": true, "until":
+                      { "condition": "PIECE_AT", "position": [1], "piece": "PAWN" } } }
+            ] }"#,
+            r#"{ "code": "ROOK", "name": "rook", "moves": [],
+                "drop_side_effects": [{ "action": "CAPTURE", "target": [1] }] }"#,
+            r#"{ "code": "ROOK", "name": "rook", "moves": [],
+                "drop_side_effects": [{ "action": "INCREMENT_VARIABLE", "variable": "DROPS",
+                    "condition": { "condition": "PIECE_AT", "position": [1], "piece": "PAWN" } }] }"#,
+            r#"{ "code": "ROOK", "name": "rook", "moves": [],
+                "drop_restrictions": [{ "condition": "PIECE_AT", "position": [1], "piece": "PAWN" }] }"#,
+        ];
+
+        for piece in pieces {
+            let mut game_spec = create_valid_game_spec();
+            game_spec.pieces = vec![serde_json::from_str(piece).unwrap()];
+
+            match game_spec.validate_specs() {
+                Err(GameSpecError::InvalidOffsetDimensions(offset)) => assert_eq!(offset, vec![1]),
+                other => panic!("Expected `InvalidOffsetDimensions` error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_condition_regions_must_fit_the_board() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.conditions = vec![serde_json::from_str(
+            r#"{ "code": "ZONE", "type": "POSITION", "check": {
+                "Player1": [[0, 0], { "relative_rank": 7 }, { "from": [2, 2], "to": [5, 5] }],
+                "Player2": [{ "union": [{ "rank": 0 }, { "file": 7 }] }]
+            } }"#,
+        )
+        .unwrap()];
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.conditions = vec![serde_json::from_str(
+            r#"{ "code": "ZONE", "type": "POSITION", "check": {
+                "Player1": [{ "difference": [{ "rank": 0 }, { "from": [0, 0], "to": [8, 0] }] }]
+            } }"#,
+        )
+        .unwrap()];
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidRegion(region)) => {
+                assert!(matches!(region, RegionSpec::Difference { .. }))
+            }
+            _ => panic!("Expected `InvalidRegion` error"),
+        }
+
+        // A region with fields of two different kinds is rejected while parsing.
+        let mixed = r#"{ "code": "ZONE", "type": "POSITION", "check": {
+            "Player1": [{ "rank": 0, "file": 1 }]
+        } }"#;
+        assert!(serde_json::from_str::<ConditionSpec>(mixed).is_err());
+    }
+
+    #[test]
+    fn test_facing_leaders_lines() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.facing_leaders = Some(FacingLeadersSpec::default());
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.facing_leaders = Some(FacingLeadersSpec {
+            lines: vec![vec![1, 1], vec![0, 0]],
+        });
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidFacingLine(line)) => assert_eq!(line, vec![0, 0]),
+            _ => panic!("Expected `InvalidFacingLine` error"),
+        }
+
+        game_spec.facing_leaders = Some(FacingLeadersSpec {
+            lines: vec![vec![0, 1, 0]],
+        });
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidFacingLine(line)) => assert_eq!(line, vec![0, 1, 0]),
+            _ => panic!("Expected `InvalidFacingLine` error"),
+        }
+    }
+
+    #[test]
+    fn test_wrap_flags() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.board.wrap = vec![true];
+
+        match game_spec.validate_specs() {
+            Err(GameSpecError::InvalidWrap(wrap)) => assert_eq!(wrap, vec![true]),
+            _ => panic!("Expected `InvalidWrap` error"),
+        }
+
+        game_spec.board.wrap = vec![true, false];
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.board.kind = HEX.to_string();
+        game_spec.board.dimensions = vec![11, 11];
+        assert!(matches!(
+            game_spec.validate_specs(),
+            Err(GameSpecError::InvalidWrap(_))
+        ));
+    }
+
+    #[test]
+    fn test_neutral_pieces() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.pieces = vec![serde_json::from_str(
+            r#"{ "code": "DUCK", "name": "duck", "moves": [] }"#,
+        )
+        .unwrap()];
+        game_spec.neutral_pieces = vec![PiecePositionSpec {
+            piece: "DUCK".to_string(),
+            positions: vec![vec![3, 3]],
+        }];
+        game_spec.placement = Some(PlacementSpec {
+            piece: "DUCK".to_string(),
+        });
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.neutral_pieces[0].positions = vec![vec![8, 3]];
+        assert!(matches!(
+            game_spec.validate_specs(),
+            Err(GameSpecError::PositionOutOfBounds(_))
+        ));
+        game_spec.neutral_pieces.clear();
+
+        game_spec.placement = Some(PlacementSpec {
+            piece: "GOOSE".to_string(),
+        });
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownPlacementPiece(piece)) => assert_eq!(piece, "GOOSE"),
+            _ => panic!("Expected `UnknownPlacementPiece` error"),
+        }
+        game_spec.placement = None;
+
+        game_spec.players.push(PlayerSpec::from_name(NEUTRAL));
+        match game_spec.validate_specs() {
+            Err(GameSpecError::ReservedPlayerName(name)) => assert_eq!(name, NEUTRAL),
+            _ => panic!("Expected `ReservedPlayerName` error"),
+        }
+    }
+
+    #[test]
+    fn test_starting_hand() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.pieces = vec![serde_json::from_str(
+            r#"{ "code": "STONE", "name": "stone", "moves": [] }"#,
+        )
+        .unwrap()];
+        game_spec.players[0].hand = vec![HandSpec {
+            piece: "STONE".to_string(),
+            count: None,
+        }];
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.players[0].hand[0].piece = "PEBBLE".to_string();
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownPieceInHand(name)) => assert_eq!(name, "PEBBLE"),
+            _ => panic!("Expected `UnknownPieceInHand` error"),
+        }
+    }
+
+    #[test]
+    fn test_continuation_moves_must_exist() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.pieces = vec![serde_json::from_str(
+            r#"{ "code": "LION", "name": "lion", "moves": [
+                { "id": 0, "step": [0, 1], "actions": [
+                    { "state": "EMPTY", "action": "MOVE", "continuation": { "moves": [0, 1] } }
+                ] },
+                { "id": 1, "step": [1, 0], "actions": [{ "state": "EMPTY", "action": "MOVE" }] }
+            ] }"#,
+        )
+        .unwrap()];
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.pieces[0].moves.pop();
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownContinuationMove(id)) => assert_eq!(id, 1),
+            _ => panic!("Expected `UnknownContinuationMove` error"),
+        }
+    }
+
+    #[test]
+    fn test_drop_conditions_must_be_checkable_on_drops() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.conditions = vec![serde_json::from_str(
+            r#"{ "code": "CORNERS", "type": "POSITION", "check": {} }"#,
+        )
+        .unwrap()];
+        game_spec.pieces = vec![serde_json::from_str(
+            r#"{ "code": "STONE", "name": "stone", "moves": [],
+                 "drop_restrictions": [{ "condition": "CORNERS" }],
+                 "drop_side_effects": [{ "action": "INCREMENT_VARIABLE", "variable": "DROPS",
+                     "condition": { "condition": "ALL", "conditions": [
+                         { "condition": "VARIABLE", "variable": "DROPS", "max": 2 },
+                         { "condition": "NOT", "conditions": [{ "condition": "PIECE_AT" }] }
+                     ] } }] }"#,
+        )
+        .unwrap()];
+
+        // Move-only codes are rejected, even when nested.
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnsupportedDropCondition(code)) => assert_eq!(code, "PIECE_AT"),
+            _ => panic!("Expected `UnsupportedDropCondition` error"),
+        }
+
+        game_spec.pieces[0].drop_side_effects[0]
+            .condition
+            .as_mut()
+            .unwrap()
+            .conditions
+            .pop();
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.pieces[0].drop_restrictions[0].condition = "FIRST_MOVE".to_string();
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnsupportedDropCondition(code)) => assert_eq!(code, "FIRST_MOVE"),
+            _ => panic!("Expected `UnsupportedDropCondition` error"),
+        }
+    }
+
+    #[test]
+    fn test_variable_scopes() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.pieces = vec![serde_json::from_str(
+            r#"{ "code": "PAWN", "name": "pawn", "moves": [
+                { "id": 0, "step": [0, 1], "actions": [{ "state": "EMPTY", "action": "MOVE" }],
+                  "conditions": [{ "condition": "NOT", "conditions": [
+                      { "condition": "VARIABLE", "variable": "MOVED", "scope": "GLOBAL" }
+                  ] }],
+                  "side_effects": [
+                      { "action": "INCREMENT_VARIABLE", "variable": "MOVED", "scope": "PLAYER" }
+                  ] }
+            ] }"#,
+        )
+        .unwrap()];
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.pieces[0].moves[0].conditions[0].conditions[0].scope = Some("TEAM".to_string());
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownVariableScope(scope)) => assert_eq!(scope, "TEAM"),
+            _ => panic!("Expected `UnknownVariableScope` error"),
+        }
+
+        game_spec.pieces[0].moves[0].conditions.clear();
+        game_spec.pieces[0].moves[0].side_effects[0].scope = Some("global".to_string());
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownVariableScope(scope)) => assert_eq!(scope, "global"),
+            _ => panic!("Expected `UnknownVariableScope` error"),
+        }
+    }
+
+    #[test]
+    fn test_custodial_directions() {
+        let mut game_spec = create_valid_game_spec();
+        game_spec.pieces = vec![serde_json::from_str(
+            r#"{ "code": "STONE", "name": "stone", "moves": [
+                { "id": 0, "step": [0, 1], "actions": [{ "state": "EMPTY", "action": "MOVE",
+                    "side_effects": [{ "action": "CUSTODIAL_CAPTURE", "directions": "ORTHOGONAL" }]
+                }] }
+            ] }"#,
+        )
+        .unwrap()];
+        assert!(game_spec.validate_specs().is_ok());
+
+        let side_effect = &mut game_spec.pieces[0].moves[0].actions[0].side_effects[0];
+        side_effect.directions = Some("ALL".to_string());
+        assert!(game_spec.validate_specs().is_ok());
+
+        let side_effect = &mut game_spec.pieces[0].moves[0].actions[0].side_effects[0];
+        side_effect.directions = Some("DIAGONAL".to_string());
+        match game_spec.validate_specs() {
+            Err(GameSpecError::UnknownCustodialDirections(directions)) => {
+                assert_eq!(directions, "DIAGONAL")
+            }
+            _ => panic!("Expected `UnknownCustodialDirections` error"),
+        }
+    }
+
+    #[test]
+    fn test_stalemate_outcome() {
+        let mut game_spec = create_valid_game_spec();
+        assert_eq!(game_spec.stalemate(), StalemateOutcome::Draw);
+
+        // The legacy flag is an alias for a loss.
+        game_spec.stalemate_loses = true;
+        assert_eq!(game_spec.stalemate(), StalemateOutcome::Loss);
+        game_spec.stalemate_outcome = Some(StalemateOutcome::Loss);
+        assert!(game_spec.validate_specs().is_ok());
+
+        game_spec.stalemate_outcome = Some(StalemateOutcome::Win);
+        match game_spec.validate_specs() {
+            Err(GameSpecError::ConflictingStalemateOutcome(outcome)) => {
+                assert_eq!(outcome, StalemateOutcome::Win)
+            }
+            _ => panic!("Expected `ConflictingStalemateOutcome` error"),
+        }
+
+        game_spec.stalemate_loses = false;
+        assert!(game_spec.validate_specs().is_ok());
+        assert_eq!(game_spec.stalemate(), StalemateOutcome::Win);
+    }
 }
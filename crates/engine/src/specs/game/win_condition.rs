@@ -1,13 +1,19 @@
 use serde::{Deserialize, Serialize};
 
 /// A win condition evaluated after every move/drop.
-/// When one fires, the moving player wins immediately.
+/// When one fires the game ends, usually in favour of the moving player. `OWN_BARE`,
+/// `CAPTURE_COUNT` and `VARIABLE` name their own winner, and `EXTINCTION` eliminates the losing
+/// players.
+/// `MAJORITY` is the exception: it only decides games that end in stalemate.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WinConditionSpec {
-    /// Discriminator: "PIECE_IN_ZONE", "OPPONENT_BARE", "CHECK_COUNT".
+    /// Discriminator: "PIECE_IN_ZONE", "OPPONENT_BARE", "CHECK_COUNT", "OWN_BARE",
+    /// "EXTINCTION", "CAPTURE_COUNT", "MAJORITY", "VARIABLE".
     pub r#type: String,
 
     /// For PIECE_IN_ZONE: the piece code that must reach the zone (e.g. "KING").
+    /// For CAPTURE_COUNT: only captures of this code count.
+    /// For MAJORITY: only pieces of this code count.
     #[serde(default)]
     pub piece: Option<String>,
 
@@ -17,10 +23,35 @@ pub struct WinConditionSpec {
 
     /// For OPPONENT_BARE: piece codes that are exempt from the "bare" check.
     /// Win is triggered when every opponent has only pieces whose codes are in this list.
+    /// For OWN_BARE: the same, but for the player's own pieces.
     #[serde(default)]
     pub exempt: Vec<String>,
 
+    /// For EXTINCTION: a player loses once every piece of any one of these codes is gone.
+    #[serde(default)]
+    pub pieces: Vec<String>,
+
     /// For CHECK_COUNT: number of checks needed to win (default 3).
+    /// For CAPTURE_COUNT: number of captures needed to win (default 1).
+    /// For VARIABLE: value the player's variable must reach (default 1).
     #[serde(default)]
     pub threshold: Option<u32>,
+
+    /// For VARIABLE: the per-player game variable that decides the game.
+    #[serde(default)]
+    pub variable: Option<String>,
+
+    /// Players the condition applies to; empty means every player. For the conditions won by
+    /// the mover (PIECE_IN_ZONE, OPPONENT_BARE, CHECK_COUNT) this is the mover, for OWN_BARE,
+    /// CAPTURE_COUNT, MAJORITY and VARIABLE the winner, and for EXTINCTION the players who can
+    /// lose by it.
+    #[serde(default)]
+    pub players: Vec<String>,
+}
+
+impl WinConditionSpec {
+    /// Whether the condition applies to `player`.
+    pub fn applies_to(&self, player: &str) -> bool {
+        self.players.is_empty() || self.players.iter().any(|p| p == player)
+    }
 }
@@ -1,10 +1,13 @@
 pub mod game;
+pub mod puzzle;
 
 pub use game::parser::parse_spec as parse_game_spec;
 pub use game::{
-    ActionSpec, BoardSpec, ConditionSpec, GameSpec, GameSpecError, MoveSpec, PieceSpec, PlayerSpec,
-    WinConditionSpec,
+    ActionSpec, BoardSpec, ConditionSpec, FacingLeadersSpec, GameSpec, GameSpecError, HandSpec,
+    MandatoryCaptureSpec, MoveSpec, PiecePositionSpec, PieceSpec, PlayerSpec, RegionSpec,
+    SideEffectSpec, StalemateOutcome, WinConditionSpec,
 };
+pub use puzzle::{parse_puzzles, PuzzleSpec};
 
 //
 
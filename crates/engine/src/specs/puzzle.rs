@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::logic::Ply;
+use crate::shared::LINE;
+use crate::specs::GameSpecError;
+
+use super::game::PiecePositionSpec;
+
+/// A tactics puzzle, to be read from a .json file holding an array of puzzles.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PuzzleSpec {
+    /// Optional label shown to the player.
+    #[serde(default)]
+    pub name: String,
+
+    /// Name of the game spec the puzzle is played in, e.g. "chess" for `specs/chess.json`.
+    pub spec: String,
+
+    /// Player to move at the start of the puzzle; this is the side the user plays.
+    pub to_move: String,
+
+    /// "LINE" (default) requires the exact solution; "MATE" also accepts any other move
+    /// that still forces a win within the remaining number of moves.
+    #[serde(default = "default_goal")]
+    pub goal: String,
+
+    /// Starting position: player name → pieces, in the same shape as `starting_positions`.
+    pub position: HashMap<String, Vec<PiecePositionSpec>>,
+
+    /// Optional starting hands: player name → piece code → count.
+    #[serde(default)]
+    pub hand: HashMap<String, HashMap<String, u32>>,
+
+    /// Expected line, alternating the user's plies and the opponent replies that are
+    /// played automatically.
+    pub solution: Vec<Ply>,
+}
+
+fn default_goal() -> String {
+    LINE.to_string()
+}
+
+/// Parses a puzzle file containing a JSON array of puzzles.
+pub fn parse_puzzles<P: AsRef<Path>>(file_path: P) -> Result<Vec<PuzzleSpec>, GameSpecError> {
+    let contents = fs::read_to_string(file_path)?;
+    let puzzles: Vec<PuzzleSpec> = serde_json::from_str(&contents)?;
+
+    Ok(puzzles)
+}
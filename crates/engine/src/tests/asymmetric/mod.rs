@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GamePhase, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::{parse_game_spec, WinConditionSpec};
+
+    fn load(spec: &str) -> Game {
+        parse_game_spec(spec)
+            .map(Game::from_spec)
+            .expect("Failed to load spec")
+    }
+
+    fn insert(game: &mut Game, pos: Vec<u8>, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Per-player leaders: only the side with a leader can be in check
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_player_without_leader_ignores_check() {
+        let mut game = load("./specs/chess.json");
+        game.player_leaders.insert("BLACK".to_string(), Vec::new());
+        game.state.pieces.clear();
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![4, 2], "ROOK", "WHITE");
+        insert(&mut game, vec![4, 5], "ROOK", "BLACK");
+        insert(&mut game, vec![4, 7], "KING", "BLACK");
+
+        assert_eq!(game.leaders("WHITE"), ["KING".to_string()]);
+        assert!(game.leaders("BLACK").is_empty());
+
+        // The rooks pin each other, but only WHITE's pin matters.
+        let plies = game.legal_plies();
+        assert!(!plies.contains(&step(vec![4, 2], vec![0, 2])));
+        assert!(plies.contains(&step(vec![4, 2], vec![4, 3])));
+
+        game.play(&step(vec![4, 2], vec![4, 3])).unwrap();
+        assert!(!game.leader_in_check());
+        assert!(game.legal_plies().contains(&step(vec![4, 5], vec![0, 5])));
+
+        // Exposing BLACK's king is not check, so the game carries on.
+        game.play(&step(vec![4, 5], vec![0, 5])).unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+    }
+
+    // -----------------------------------------------------------------------
+    // Scoped win conditions only fire for the listed players
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_scoped_piece_in_zone_ignores_other_players() {
+        let mut game = load("./specs/king_of_the_hill.json");
+        game.win_conditions[0].players = vec!["WHITE".to_string()];
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "KING", "WHITE");
+        insert(&mut game, vec![3, 5], "KING", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![0, 1])).unwrap();
+        game.play(&step(vec![3, 5], vec![3, 4])).unwrap();
+
+        assert_eq!(
+            game.state.phase,
+            GamePhase::Idle,
+            "BLACK cannot win by the hill"
+        );
+    }
+
+    #[test]
+    fn test_scoped_extinction_spares_players_without_the_piece() {
+        // A tafl-like setup: only BLACK has a king, and only BLACK loses when it is gone.
+        let mut game = load("./specs/chess.json");
+        game.leader.clear();
+        game.win_conditions = vec![serde_json::from_str::<WinConditionSpec>(
+            r#"{ "type": "EXTINCTION", "pieces": ["KING"], "players": ["BLACK"] }"#,
+        )
+        .unwrap()];
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![7, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+        insert(&mut game, vec![3, 6], "PAWN", "BLACK");
+
+        // WHITE has no king from the start, yet is not eliminated.
+        game.play(&step(vec![0, 0], vec![0, 1])).unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+
+        game.play(&step(vec![3, 6], vec![3, 5])).unwrap();
+        game.play(&step(vec![7, 0], vec![7, 7])).unwrap();
+
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+}
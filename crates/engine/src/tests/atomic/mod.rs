@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GamePhase, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::{parse_game_spec, GameSpec};
+
+    fn load_spec() -> GameSpec {
+        parse_game_spec("./specs/atomic.json").expect("Failed to load atomic spec")
+    }
+
+    /// Atomic chess with an empty board apart from the two kings.
+    fn bare_game(spec: GameSpec, white_king: Position, black_king: Position) -> Game {
+        let mut game = Game::from_spec(spec);
+        game.state.pieces.clear();
+        insert(&mut game, white_king, "KING", "WHITE");
+        insert(&mut game, black_king, "KING", "BLACK");
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // The blast
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_capture_explodes_capturer_and_neighbours() {
+        let mut game = bare_game(load_spec(), vec![4, 0], vec![4, 7]);
+        insert(&mut game, vec![2, 2], "KNIGHT", "WHITE");
+        insert(&mut game, vec![3, 4], "BISHOP", "BLACK");
+        insert(&mut game, vec![4, 5], "ROOK", "BLACK");
+        insert(&mut game, vec![2, 5], "BISHOP", "WHITE");
+        insert(&mut game, vec![3, 5], "PAWN", "BLACK");
+
+        game.play(&step(vec![2, 2], vec![3, 4])).unwrap();
+
+        // The knight, its victim and both neighbouring pieces are gone; the pawn survives.
+        assert!(game.piece_at_position(&vec![3, 4]).is_none());
+        assert!(game.piece_at_position(&vec![4, 5]).is_none());
+        assert!(game.piece_at_position(&vec![2, 5]).is_none());
+        assert!(game.piece_at_position(&vec![3, 5]).is_some());
+        assert_eq!(game.state.phase, GamePhase::Idle);
+    }
+
+    #[test]
+    fn test_radius_widens_the_blast() {
+        let mut spec = load_spec();
+        spec.pieces
+            .iter_mut()
+            .flat_map(|piece| piece.moves.iter_mut())
+            .flat_map(|m| m.actions.iter_mut())
+            .flat_map(|action| action.side_effects.iter_mut())
+            .for_each(|side_effect| side_effect.radius = Some(2));
+
+        let mut game = bare_game(spec, vec![4, 0], vec![4, 7]);
+        insert(&mut game, vec![2, 2], "KNIGHT", "WHITE");
+        insert(&mut game, vec![3, 4], "BISHOP", "BLACK");
+        insert(&mut game, vec![5, 6], "QUEEN", "BLACK");
+
+        game.play(&step(vec![2, 2], vec![3, 4])).unwrap();
+
+        assert!(game.piece_at_position(&vec![5, 6]).is_none());
+        assert!(game.piece_at_position(&vec![4, 7]).is_some());
+    }
+
+    #[test]
+    fn test_capturing_pawn_does_not_promote() {
+        let mut game = bare_game(load_spec(), vec![4, 0], vec![4, 7]);
+        insert(&mut game, vec![1, 6], "PAWN", "WHITE");
+        insert(&mut game, vec![0, 7], "ROOK", "BLACK");
+        insert(&mut game, vec![7, 1], "ROOK", "WHITE");
+
+        game.play(&step(vec![1, 6], vec![0, 7])).unwrap();
+
+        assert!(game.piece_at_position(&vec![0, 7]).is_none());
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.current_player(), "BLACK");
+    }
+
+    // -----------------------------------------------------------------------
+    // Legality around the leaders
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_exploding_own_king_is_illegal() {
+        let mut game = bare_game(load_spec(), vec![4, 0], vec![4, 7]);
+        insert(&mut game, vec![4, 1], "KNIGHT", "BLACK");
+        insert(&mut game, vec![0, 1], "ROOK", "WHITE");
+
+        let plies = game.legal_plies();
+
+        // Neither the king nor the rook may capture next to the white king.
+        assert!(!plies.contains(&step(vec![4, 0], vec![4, 1])));
+        assert!(!plies.contains(&step(vec![0, 1], vec![4, 1])));
+        assert!(plies.contains(&step(vec![0, 1], vec![3, 1])));
+    }
+
+    #[test]
+    fn test_exploding_enemy_king_wins_even_in_check() {
+        let mut game = bare_game(load_spec(), vec![0, 0], vec![4, 7]);
+        insert(&mut game, vec![0, 7], "ROOK", "BLACK");
+        insert(&mut game, vec![5, 6], "BISHOP", "BLACK");
+        insert(&mut game, vec![5, 2], "QUEEN", "WHITE");
+        assert!(game.leader_in_check());
+
+        game.play(&step(vec![5, 2], vec![5, 6])).unwrap();
+
+        assert!(game.piece_at_position(&vec![4, 7]).is_none());
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+}
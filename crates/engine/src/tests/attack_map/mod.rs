@@ -5,7 +5,7 @@
 // than hand-coding representative archetypes here.
 #[cfg(test)]
 mod tests {
-    use crate::logic::{Game, Piece};
+    use crate::logic::{Game, GameTransition, Piece, PieceState};
     use crate::shared::Position;
     use crate::specs::parse_game_spec;
 
@@ -170,4 +170,196 @@ mod tests {
             );
         }
     }
+
+    // -------------------------------------------------------------------------
+    // Query API: attackers, defenders, checkers and pins
+    // -------------------------------------------------------------------------
+
+    /// WHITE DUMMY at [5,5], defended by WHITE LINE_ATTACKER [5,2] and
+    /// DIAGONAL_ATTACKER [3,3]. Attacked by BLACK JUMP_ATTACKER [6,7] and
+    /// LINE_ATTACKER [0,5]; BLACK LINE_ATTACKER [9,5] is blocked by WHITE DUMMY [7,5].
+    #[test]
+    fn test_square_control_lists_attackers_and_defenders() {
+        let mut game = load_game();
+        insert(&mut game, vec![5, 5], "DUMMY", "WHITE");
+        insert(&mut game, vec![5, 2], "LINE_ATTACKER", "WHITE");
+        insert(&mut game, vec![3, 3], "DIAGONAL_ATTACKER", "WHITE");
+        insert(&mut game, vec![7, 5], "DUMMY", "WHITE");
+        insert(&mut game, vec![6, 7], "JUMP_ATTACKER", "BLACK");
+        insert(&mut game, vec![0, 5], "LINE_ATTACKER", "BLACK");
+        insert(&mut game, vec![9, 5], "LINE_ATTACKER", "BLACK");
+
+        let control = game.square_control(&vec![5, 5], "WHITE");
+
+        assert_eq!(control.attackers, vec![vec![0, 5], vec![6, 7]]);
+        assert_eq!(control.defenders, vec![vec![3, 3], vec![5, 2]]);
+        assert_eq!(control.attack_count(), 2);
+        assert_eq!(control.defense_count(), 2);
+
+        // The same square seen from BLACK's side swaps the roles.
+        assert_eq!(game.attackers_of(&vec![5, 5], "BLACK"), control.attackers);
+    }
+
+    /// Chess: WHITE KING [4,0] in double check from BLACK ROOK [4,7] and KNIGHT [3,2].
+    #[test]
+    fn test_checkers_reports_every_checking_piece() {
+        let mut game = parse_game_spec("./specs/chess.json")
+            .map(Game::from_spec)
+            .unwrap();
+        game.state.pieces.clear();
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![4, 7], "ROOK", "BLACK");
+        insert(&mut game, vec![3, 2], "KNIGHT", "BLACK");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+
+        let checkers = game.checkers("WHITE");
+        assert_eq!(checkers.len(), 1);
+        assert_eq!(checkers[&vec![4u8, 0u8]], vec![vec![3, 2], vec![4, 7]]);
+        assert!(game.checkers("BLACK").is_empty());
+    }
+
+    /// Chess: WHITE KING [4,0].
+    /// - ROOK [4,1] pinned along the file by BLACK ROOK [4,7].
+    /// - KNIGHT [5,1] pinned along the diagonal by BLACK BISHOP [7,3].
+    /// - PAWNS [2,2] and [3,1] both stand between BLACK QUEEN [0,4] and the king: no pin.
+    #[test]
+    fn test_pins_report_pinner_and_line() {
+        let mut game = parse_game_spec("./specs/chess.json")
+            .map(Game::from_spec)
+            .unwrap();
+        game.state.pieces.clear();
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![4, 1], "ROOK", "WHITE");
+        insert(&mut game, vec![5, 1], "KNIGHT", "WHITE");
+        insert(&mut game, vec![2, 2], "PAWN", "WHITE");
+        insert(&mut game, vec![3, 1], "PAWN", "WHITE");
+        insert(&mut game, vec![4, 7], "ROOK", "BLACK");
+        insert(&mut game, vec![7, 3], "BISHOP", "BLACK");
+        insert(&mut game, vec![0, 4], "QUEEN", "BLACK");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+
+        let pins = game.pins("WHITE");
+
+        assert_eq!(pins.len(), 2, "Unexpected pins: {:?}", pins);
+
+        assert_eq!(pins[0].pinned, vec![4, 1]);
+        assert_eq!(pins[0].pinner, vec![4, 7]);
+        assert_eq!(pins[0].leader, vec![4, 0]);
+        assert_eq!(pins[0].direction, vec![0, -1]);
+        assert_eq!(
+            pins[0].line,
+            (1u8..=7)
+                .rev()
+                .map(|y| vec![4, y])
+                .collect::<Vec<Position>>()
+        );
+
+        assert_eq!(pins[1].pinned, vec![5, 1]);
+        assert_eq!(pins[1].pinner, vec![7, 3]);
+        assert_eq!(pins[1].direction, vec![-1, -1]);
+        assert_eq!(pins[1].line, vec![vec![7, 3], vec![6, 2], vec![5, 1]]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Threats derived from the board changes a move would produce
+    // -------------------------------------------------------------------------
+
+    /// Janggi cannon: it only attacks over exactly one screen.
+    /// CHO GENERAL [4,1], HAN CANNON [4,6]. With an empty file the general is safe;
+    /// a HAN GENERAL on [4,3] as screen puts it in check.
+    #[test]
+    fn test_cannon_attacks_only_over_a_screen() {
+        let mut game = parse_game_spec("./specs/janggi_cannon.json")
+            .map(Game::from_spec)
+            .unwrap();
+        game.state.pieces.clear();
+        insert(&mut game, vec![4, 1], "GENERAL", "CHO");
+        insert(&mut game, vec![4, 6], "CANNON", "HAN");
+        insert(&mut game, vec![0, 9], "GENERAL", "HAN");
+
+        assert!(!game.attacked_by("HAN").contains(&vec![4u8, 1u8]));
+        assert!(!game.leader_in_check());
+
+        game.state.pieces.remove(&vec![0u8, 9u8]);
+        insert(&mut game, vec![4, 3], "GENERAL", "HAN");
+
+        assert!(game.leader_in_check());
+        assert_eq!(game.checkers("CHO")[&vec![4u8, 1u8]], vec![vec![4, 6]]);
+    }
+
+    /// Chess en passant: BLACK PAWN [3,4] attacks the WHITE PAWN [4,4] only while it
+    /// carries the EN_PASSANT flag, through the CAPTURE side effect.
+    #[test]
+    fn test_en_passant_capture_is_a_threat() {
+        let mut game = parse_game_spec("./specs/chess.json")
+            .map(Game::from_spec)
+            .unwrap();
+        game.state.pieces.clear();
+        insert(&mut game, vec![3, 4], "PAWN", "BLACK");
+        insert(&mut game, vec![4, 4], "PAWN", "WHITE");
+
+        assert!(!game.attacked_by("BLACK").contains(&vec![4u8, 4u8]));
+
+        game.state
+            .pieces
+            .get_mut(&vec![4u8, 4u8])
+            .unwrap()
+            .state
+            .insert("EN_PASSANT".to_string(), PieceState::Uint(1));
+
+        let threats = game.attacked_by("BLACK");
+        assert!(threats.contains(&vec![4u8, 4u8]));
+        assert!(threats.contains(&vec![2u8, 3u8]));
+    }
+
+    /// Ataxx: RED STONE [2,2] can move next to BLUE STONE [4,2] and convert it.
+    #[test]
+    fn test_conversion_is_a_threat() {
+        let mut game = parse_game_spec("./specs/ataxx.json")
+            .map(Game::from_spec)
+            .unwrap();
+        game.state.pieces.clear();
+        insert(&mut game, vec![2, 2], "STONE", "RED");
+        insert(&mut game, vec![4, 2], "STONE", "BLUE");
+        insert(&mut game, vec![6, 6], "STONE", "BLUE");
+
+        let threats = game.attacked_by("RED");
+        assert!(threats.contains(&vec![4u8, 2u8]));
+        assert!(!threats.contains(&vec![6u8, 6u8]));
+    }
+
+    /// JUMP_CAPTURER [5,5] lands on [5,7] and removes the BLACK DUMMY on [5,6]: the
+    /// captured square is attacked, the landing square is not.
+    #[test]
+    fn test_capture_away_from_landing_square() {
+        let mut game = load_game();
+        insert(&mut game, vec![5, 5], "JUMP_CAPTURER", "WHITE");
+        insert(&mut game, vec![5, 6], "DUMMY", "BLACK");
+
+        let threats = game.attacked_by("WHITE");
+        assert!(threats.contains(&vec![5u8, 6u8]));
+        assert!(!threats.contains(&vec![5u8, 7u8]));
+    }
+
+    /// `"attacks": false` keeps a capturing move out of the attack map without
+    /// stopping it from capturing.
+    #[test]
+    fn test_attacks_flag_opts_move_out() {
+        let mut game = load_game();
+        insert(&mut game, vec![5, 5], "SHY_CAPTURER", "WHITE");
+        insert(&mut game, vec![5, 6], "DUMMY", "BLACK");
+
+        assert!(game.attacked_by("WHITE").is_empty());
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![5, 5],
+        })
+        .unwrap();
+        assert!(game
+            .state
+            .available_moves
+            .as_ref()
+            .unwrap()
+            .contains_key(&vec![5u8, 6u8]));
+    }
 }
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GamePhase, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    /// Empty 6×6 board; BLACK only has a walker on [5,4] so it always has a reply.
+    fn load_game() -> Game {
+        let mut game = parse_game_spec("./src/tests/combinators/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load combinators test spec");
+        insert(&mut game, vec![5, 4], "WALKER", "BLACK");
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Move conditions
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_any_passes_when_one_branch_holds() {
+        let mut game = load_game();
+        insert(&mut game, vec![2, 0], "ESCORTED", "WHITE");
+        let advance = step(vec![2, 0], vec![2, 1]);
+        assert!(!game.legal_plies().contains(&advance));
+
+        insert(&mut game, vec![1, 0], "DUMMY", "WHITE");
+        assert!(game.legal_plies().contains(&advance));
+
+        game.state.pieces.remove(&vec![1, 0]);
+        insert(&mut game, vec![3, 0], "DUMMY", "WHITE");
+        assert!(game.legal_plies().contains(&advance));
+    }
+
+    #[test]
+    fn test_all_with_nested_not() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 2], "OPENER", "WHITE");
+        assert!(game.legal_plies().contains(&step(vec![0, 2], vec![1, 2])));
+
+        // A dummy on the square ahead negates the NOT branch.
+        insert(&mut game, vec![0, 3], "DUMMY", "WHITE");
+        assert!(!game.legal_plies().contains(&step(vec![0, 2], vec![1, 2])));
+
+        // Without the dummy, only the first move passes the FIRST_MOVE branch.
+        game.state.pieces.remove(&vec![0, 3]);
+        game.state.pieces.get_mut(&vec![0, 2]).unwrap().total_moves = 1;
+        assert!(!game.legal_plies().contains(&step(vec![0, 2], vec![1, 2])));
+    }
+
+    #[test]
+    fn test_not_named_condition_checks_the_target_square() {
+        let mut game = load_game();
+        insert(&mut game, vec![1, 2], "OUTSIDER", "WHITE");
+        let plies = game.legal_plies();
+
+        // [2,2] is in CENTER, [1,3] is not.
+        assert!(!plies.contains(&step(vec![1, 2], vec![2, 2])));
+        assert!(plies.contains(&step(vec![1, 2], vec![1, 3])));
+    }
+
+    #[test]
+    fn test_any_named_condition_limits_the_landing_squares() {
+        let mut game = load_game();
+        // Clear BLACK's walker out of the east file.
+        game.state.pieces.remove(&vec![5, 4]);
+        insert(&mut game, vec![0, 0], "GOALIE", "WHITE");
+        insert(&mut game, vec![5, 0], "GOALIE", "WHITE");
+        let mut targets: Vec<Position> = game
+            .legal_plies()
+            .into_iter()
+            .filter_map(|ply| match ply {
+                Ply::Move { to, .. } => Some(to),
+                _ => None,
+            })
+            .collect();
+        targets.sort();
+
+        // Each slide only stops on its file's goal square.
+        assert_eq!(targets, vec![vec![0, 5], vec![5, 5]]);
+    }
+
+    // -----------------------------------------------------------------------
+    // Modifiers and repeat.until
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_modifier_fires_on_any_goal() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "RUNNER", "WHITE");
+        insert(&mut game, vec![2, 0], "RUNNER", "WHITE");
+
+        // The middle file has no goal, so the runner just moves.
+        game.play(&step(vec![2, 0], vec![2, 5])).unwrap();
+        assert_eq!(game.piece_at_position(&vec![2, 5]).unwrap().code, "RUNNER");
+        game.play(&step(vec![5, 4], vec![4, 4])).unwrap();
+
+        game.play(&Ply::Move {
+            from: vec![0, 0],
+            to: vec![0, 5],
+            promotion: Some("DUMMY".to_string()),
+        })
+        .unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.piece_at_position(&vec![0, 5]).unwrap().code, "DUMMY");
+    }
+
+    #[test]
+    fn test_until_stops_on_any_branch() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "SLIDER", "WHITE");
+        insert(&mut game, vec![0, 2], "SLIDER", "WHITE");
+        insert(&mut game, vec![4, 2], "DUMMY", "BLACK");
+        let plies = game.legal_plies();
+
+        // The barrier stops the first slider, the black dummy the second.
+        assert!(plies.contains(&step(vec![0, 0], vec![3, 0])));
+        assert!(!plies.contains(&step(vec![0, 0], vec![4, 0])));
+        assert!(plies.contains(&step(vec![0, 2], vec![3, 2])));
+        assert!(!plies.contains(&step(vec![0, 2], vec![5, 2])));
+    }
+
+    // -----------------------------------------------------------------------
+    // Drop restrictions
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_not_restriction_only_allows_center_drops() {
+        let game = load_game();
+
+        let mut drops: Vec<Position> = game
+            .legal_plies()
+            .into_iter()
+            .filter_map(|ply| match ply {
+                Ply::Drop { to, .. } => Some(to),
+                _ => None,
+            })
+            .collect();
+        drops.sort();
+
+        assert_eq!(drops, vec![vec![2, 2], vec![2, 3], vec![3, 2], vec![3, 3]]);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    /// Empty 6×6 board; BLACK only has a walker on [5,4] so it always has a reply.
+    fn load_game() -> Game {
+        let mut game = parse_game_spec("./src/tests/conditional_side_effects/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load conditional side effects test spec");
+        insert(&mut game, vec![5, 4], "WALKER", "BLACK");
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    /// Plays a quiet BLACK reply, shuffling the walker along the top ranks.
+    fn black_waits(game: &mut Game) {
+        let (from, to) = if game.piece_at_position(&vec![5, 4]).is_some() {
+            (vec![5, 4], vec![4, 4])
+        } else {
+            (vec![4, 4], vec![5, 4])
+        };
+        game.play(&step(from, to)).unwrap();
+    }
+
+    /// Moves the WHITE withdrawer away from a BLACK piece of `code` standing behind it.
+    fn withdraw_from(code: &str) -> Game {
+        let mut game = load_game();
+        insert(&mut game, vec![2, 1], "WITHDRAWER", "WHITE");
+        insert(&mut game, vec![2, 0], code, "BLACK");
+        game.play(&step(vec![2, 1], vec![2, 2])).unwrap();
+        game
+    }
+
+    // -----------------------------------------------------------------------
+    // Conditions on move side effects
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_capture_fires_when_condition_holds() {
+        let game = withdraw_from("PAWN");
+        assert!(game.piece_at_position(&vec![2, 0]).is_none());
+    }
+
+    #[test]
+    fn test_capture_skipped_when_condition_fails() {
+        let game = withdraw_from("DUMMY");
+        assert_eq!(game.piece_at_position(&vec![2, 0]).unwrap().code, "DUMMY");
+    }
+
+    #[test]
+    fn test_options_stand_in_for_condition_piece() {
+        let game = withdraw_from("SOLDIER");
+        assert!(game.piece_at_position(&vec![2, 0]).is_none());
+    }
+
+    #[test]
+    fn test_first_move_condition_on_side_effects() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "RECRUIT", "WHITE");
+
+        game.play(&step(vec![0, 0], vec![1, 0])).unwrap();
+        black_waits(&mut game);
+        game.play(&step(vec![1, 0], vec![2, 0])).unwrap();
+
+        let recruit = game.piece_at_position(&vec![2, 0]).unwrap();
+        assert!(recruit.state.contains_key("OPENED"));
+        assert_eq!(recruit.counter("EARLY_STEPS"), 1);
+    }
+
+    // -----------------------------------------------------------------------
+    // Conditions on drop side effects
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_drop_side_effect_condition_checked_at_drop_square() {
+        let mut game = load_game();
+
+        game.play(&Ply::Drop {
+            piece_code: "STONE".to_string(),
+            to: vec![0, 0],
+        })
+        .unwrap();
+        black_waits(&mut game);
+        game.play(&Ply::Drop {
+            piece_code: "STONE".to_string(),
+            to: vec![2, 2],
+        })
+        .unwrap();
+
+        assert_eq!(game.variable("CORNER_DROPS", None, "WHITE"), 1);
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GamePhase, Piece, PieceState, Ply};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    /// Empty 6×6 board; BLACK only has a walker on [5,5] so it always has a reply.
+    fn load_game() -> Game {
+        let mut game = parse_game_spec("./src/tests/counters/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load counters test spec");
+        insert(&mut game, vec![5, 5], "WALKER", "BLACK");
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    /// Plays a quiet BLACK reply, shuffling the walker along the top rank.
+    fn black_waits(game: &mut Game) {
+        let (from, to) = if game.piece_at_position(&vec![5, 5]).is_some() {
+            (vec![5, 5], vec![4, 5])
+        } else {
+            (vec![4, 5], vec![5, 5])
+        };
+        game.play(&step(from, to)).unwrap();
+    }
+
+    fn counter(game: &Game, pos: Position, name: &str) -> u16 {
+        game.piece_at_position(&pos).unwrap().counter(name)
+    }
+
+    // -----------------------------------------------------------------------
+    // INCREMENT on the moving piece, read by COUNTER conditions
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_piece_promotes_after_three_captures() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "HUNTER", "WHITE");
+        for rank in 1..=3 {
+            insert(&mut game, vec![0, rank], "DUMMY", "BLACK");
+        }
+
+        game.play(&step(vec![0, 0], vec![0, 1])).unwrap();
+        black_waits(&mut game);
+        game.play(&step(vec![0, 1], vec![0, 2])).unwrap();
+        black_waits(&mut game);
+        assert_eq!(counter(&game, vec![0, 2], "KILLS"), 2);
+
+        // The third capture triggers the promotion.
+        game.play(&Ply::Move {
+            from: vec![0, 2],
+            to: vec![0, 3],
+            promotion: Some("CHAMPION".to_string()),
+        })
+        .unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(
+            game.piece_at_position(&vec![0, 3]).unwrap().code,
+            "CHAMPION"
+        );
+    }
+
+    #[test]
+    fn test_counter_condition_limits_moves() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "RUNNER", "WHITE");
+
+        game.play(&step(vec![0, 0], vec![1, 0])).unwrap();
+        black_waits(&mut game);
+        game.play(&step(vec![1, 0], vec![2, 0])).unwrap();
+        black_waits(&mut game);
+
+        assert_eq!(
+            game.piece_at_position(&vec![2, 0])
+                .unwrap()
+                .state
+                .get("STEPS"),
+            Some(&PieceState::Counter(2))
+        );
+        assert!(!game.legal_plies().contains(&step(vec![2, 0], vec![3, 0])));
+    }
+
+    // -----------------------------------------------------------------------
+    // Counters on other pieces
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_shot_wounds_the_captured_piece() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "ARCHER", "WHITE");
+        insert(&mut game, vec![0, 3], "DUMMY", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![0, 3])).unwrap();
+
+        // The target survives the shot, one wound heavier, and is not tallied as captured.
+        let dummy = game.piece_at_position(&vec![0, 3]).unwrap();
+        assert_eq!(dummy.player, "BLACK");
+        assert_eq!(dummy.counter("WOUNDS"), 1);
+        assert!(!game.state.captures.contains_key("WHITE"));
+    }
+
+    #[test]
+    fn test_decrement_and_reset_at_relative_target() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "MEDIC", "WHITE");
+        insert(&mut game, vec![1, 0], "DUMMY", "WHITE");
+        insert(&mut game, vec![1, 1], "DUMMY", "WHITE");
+        game.state
+            .pieces
+            .get_mut(&vec![1, 0])
+            .unwrap()
+            .set_counter("WOUNDS", 3);
+        game.state
+            .pieces
+            .get_mut(&vec![1, 1])
+            .unwrap()
+            .set_counter("WOUNDS", 5);
+
+        // Stepping forward heals the neighbour of the source square by two.
+        game.play(&step(vec![0, 0], vec![0, 1])).unwrap();
+        assert_eq!(counter(&game, vec![1, 0], "WOUNDS"), 1);
+        black_waits(&mut game);
+
+        // Stepping back clears the counter of the neighbour altogether.
+        game.play(&step(vec![0, 1], vec![0, 0])).unwrap();
+        assert!(game
+            .piece_at_position(&vec![1, 1])
+            .unwrap()
+            .state
+            .is_empty());
+    }
+}
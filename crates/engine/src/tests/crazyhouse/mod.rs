@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::logic::{Game, GamePhase, GameTransition, Piece};
+    use crate::shared::UNLIMITED;
     use crate::specs::parse_game_spec;
 
     fn load_game() -> Game {
@@ -325,4 +326,39 @@ mod tests {
             .unwrap_or(0);
         assert_eq!(count, 0, "BISHOP count in hand should be 0 after dropping");
     }
+
+    // -----------------------------------------------------------------------
+    // Test 8 — Capturing into an unlimited supply keeps it unlimited.
+    //
+    // WHITE starts with an unlimited supply of PAWNs and captures a BLACK PAWN.
+    // The count must not overflow (and wrap to an empty supply).
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_capture_into_unlimited_hand_stays_unlimited() {
+        let mut game = load_game();
+        game.state.pieces.clear();
+        insert(&mut game, vec![4, 4], "ROOK", "WHITE");
+        insert(&mut game, vec![4, 5], "PAWN", "BLACK");
+        insert(&mut game, vec![0, 7], "KING", "BLACK");
+        insert(&mut game, vec![0, 0], "KING", "WHITE");
+        game.state
+            .hand
+            .entry("WHITE".to_string())
+            .or_default()
+            .insert("PAWN".to_string(), UNLIMITED);
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![4, 4],
+        })
+        .unwrap();
+        game.transition(GameTransition::ExecuteMove {
+            position: vec![4, 5],
+        })
+        .unwrap();
+
+        assert_eq!(
+            game.hand().get("WHITE").and_then(|h| h.get("PAWN")),
+            Some(&UNLIMITED)
+        );
+    }
 }
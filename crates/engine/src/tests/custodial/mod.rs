@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GamePhase, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    /// Tafl-like game on a 7×7 board: every piece slides like a rook and captures by custody.
+    /// The DEFENDERS' king is only captured when enclosed, and the corners are hostile.
+    fn load_game() -> Game {
+        let mut game = parse_game_spec("./src/tests/custodial/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load custodial test spec");
+        // The DEFENDERS lose once their king is gone, so keep one out of the way.
+        insert(&mut game, vec![6, 3], "KING", "DEFENDERS");
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Flanking between the moving piece and an ally
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_flanked_enemy_is_captured() {
+        let mut game = load_game();
+        insert(&mut game, vec![4, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![2, 0], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![3, 3], "SOLDIER", "DEFENDERS");
+
+        game.play(&step(vec![2, 0], vec![2, 3])).unwrap();
+
+        assert!(game.piece_at_position(&vec![3, 3]).is_none());
+        assert_eq!(game.state.phase, GamePhase::Idle);
+    }
+
+    #[test]
+    fn test_moving_between_two_enemies_is_safe() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![2, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![4, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![3, 0], "SOLDIER", "DEFENDERS");
+
+        game.play(&step(vec![0, 0], vec![0, 1])).unwrap();
+        game.play(&step(vec![3, 0], vec![3, 3])).unwrap();
+
+        assert_eq!(
+            game.piece_at_position(&vec![3, 3]).map(|p| p.player),
+            Some("DEFENDERS".to_string())
+        );
+        assert!(game.piece_at_position(&vec![2, 3]).is_some());
+        assert!(game.piece_at_position(&vec![4, 3]).is_some());
+    }
+
+    #[test]
+    fn test_diagonal_flank_needs_all_directions() {
+        let mut game = load_game();
+        insert(&mut game, vec![4, 4], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![2, 0], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![3, 3], "SOLDIER", "DEFENDERS");
+
+        // The soldier only captures orthogonally.
+        game.play(&step(vec![2, 0], vec![2, 2])).unwrap();
+        assert!(game.piece_at_position(&vec![3, 3]).is_some());
+
+        // The archer's custody runs along the diagonals too.
+        let mut game = load_game();
+        insert(&mut game, vec![4, 4], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![2, 0], "ARCHER", "ATTACKERS");
+        insert(&mut game, vec![3, 3], "SOLDIER", "DEFENDERS");
+
+        game.play(&step(vec![2, 0], vec![2, 2])).unwrap();
+        assert!(game.piece_at_position(&vec![3, 3]).is_none());
+    }
+
+    // -----------------------------------------------------------------------
+    // Hostile squares and immune pieces
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_hostile_corner_flanks_like_an_ally() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 4], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![0, 1], "SOLDIER", "DEFENDERS");
+
+        game.play(&step(vec![0, 4], vec![0, 2])).unwrap();
+
+        assert!(game.piece_at_position(&vec![0, 1]).is_none());
+    }
+
+    #[test]
+    fn test_immune_piece_is_never_captured() {
+        let mut game = load_game();
+        insert(&mut game, vec![4, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![2, 0], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![3, 3], "SHIELD", "DEFENDERS");
+
+        game.play(&step(vec![2, 0], vec![2, 3])).unwrap();
+
+        assert!(game.piece_at_position(&vec![3, 3]).is_some());
+    }
+
+    // -----------------------------------------------------------------------
+    // The king must be enclosed on every side
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_enclosed_piece_needs_every_side() {
+        let mut game = load_game();
+        game.state.pieces.clear();
+        insert(&mut game, vec![3, 3], "KING", "DEFENDERS");
+        insert(&mut game, vec![2, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![3, 4], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![6, 3], "SOLDIER", "ATTACKERS");
+
+        // Surrounded on three sides only: not captured.
+        game.play(&step(vec![6, 3], vec![4, 3])).unwrap();
+        assert!(game.piece_at_position(&vec![3, 3]).is_some());
+        assert_eq!(game.state.phase, GamePhase::Idle);
+    }
+
+    #[test]
+    fn test_enclosed_king_is_captured_and_loses() {
+        let mut game = load_game();
+        game.state.pieces.clear();
+        insert(&mut game, vec![3, 3], "KING", "DEFENDERS");
+        insert(&mut game, vec![2, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![4, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![3, 4], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![6, 2], "SOLDIER", "ATTACKERS");
+
+        game.play(&step(vec![6, 2], vec![3, 2])).unwrap();
+
+        assert!(game.piece_at_position(&vec![3, 3]).is_none());
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("ATTACKERS".to_string())
+            }
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Custodial threats show in the attack map
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_flanking_move_attacks_the_enemy() {
+        let mut game = load_game();
+        insert(&mut game, vec![4, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![2, 0], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![3, 3], "SOLDIER", "DEFENDERS");
+
+        assert_eq!(
+            game.attackers_of(&vec![3, 3], "ATTACKERS"),
+            vec![vec![2, 0]]
+        );
+        // The king flanks [4,3] against its own soldier by sliding to [5,3].
+        assert_eq!(
+            game.attackers_of(&vec![4, 3], "DEFENDERS"),
+            vec![vec![6, 3]]
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Captured pieces go to the hand
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_custodial_capture_fills_the_hand() {
+        let mut game = load_game();
+        game.hand_enabled = true;
+        insert(&mut game, vec![4, 3], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![2, 0], "SOLDIER", "ATTACKERS");
+        insert(&mut game, vec![3, 3], "SOLDIER", "DEFENDERS");
+
+        game.play(&step(vec![2, 0], vec![2, 3])).unwrap();
+
+        assert_eq!(game.state.hand["ATTACKERS"]["SOLDIER"], 1);
+    }
+}
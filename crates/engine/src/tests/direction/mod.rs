@@ -10,7 +10,7 @@ mod tests {
 
     #[test]
     fn test_identity_matrix_is_unchanged() {
-        let identity = [[1, 0], [0, 1]];
+        let identity = vec![vec![1, 0], vec![0, 1]];
         assert_eq!(apply_direction(&identity, &vec![0, 1]), vec![0, 1]);
         assert_eq!(apply_direction(&identity, &vec![1, 0]), vec![1, 0]);
         assert_eq!(apply_direction(&identity, &vec![1, 1]), vec![1, 1]);
@@ -18,7 +18,7 @@ mod tests {
 
     #[test]
     fn test_180_rotation_reverses_both_axes() {
-        let rotate_180 = [[-1, 0], [0, -1]];
+        let rotate_180 = vec![vec![-1, 0], vec![0, -1]];
         assert_eq!(apply_direction(&rotate_180, &vec![0, 1]), vec![0, -1]);
         assert_eq!(apply_direction(&rotate_180, &vec![1, 0]), vec![-1, 0]);
         assert_eq!(apply_direction(&rotate_180, &vec![1, 1]), vec![-1, -1]);
@@ -28,7 +28,7 @@ mod tests {
     #[test]
     fn test_90_clockwise_rotation() {
         // SILVER: canonical [0, 1] (forward/up) becomes [1, 0] (right)
-        let rotate_90cw = [[0, 1], [-1, 0]];
+        let rotate_90cw = vec![vec![0, 1], vec![-1, 0]];
         assert_eq!(apply_direction(&rotate_90cw, &vec![0, 1]), vec![1, 0]); // up    → right
         assert_eq!(apply_direction(&rotate_90cw, &vec![0, -1]), vec![-1, 0]); // down  → left
         assert_eq!(apply_direction(&rotate_90cw, &vec![1, 0]), vec![0, -1]); // right → down
@@ -38,7 +38,7 @@ mod tests {
     #[test]
     fn test_90_counter_clockwise_rotation() {
         // GOLD: canonical [0, 1] (forward/up) becomes [-1, 0] (left)
-        let rotate_90ccw = [[0, -1], [1, 0]];
+        let rotate_90ccw = vec![vec![0, -1], vec![1, 0]];
         assert_eq!(apply_direction(&rotate_90ccw, &vec![0, 1]), vec![-1, 0]); // up    → left
         assert_eq!(apply_direction(&rotate_90ccw, &vec![0, -1]), vec![1, 0]); // down  → right
         assert_eq!(apply_direction(&rotate_90ccw, &vec![1, 0]), vec![0, 1]); // right → up
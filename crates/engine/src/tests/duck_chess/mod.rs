@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GameError, GamePhase, GameTransition, Piece, Ply};
+    use crate::shared::{Position, NEUTRAL, PLACE};
+    use crate::specs::parse_game_spec;
+
+    fn load_duck_chess() -> Game {
+        parse_game_spec("./specs/duck_chess.json")
+            .map(Game::from_spec)
+            .expect("Failed to load duck_chess spec")
+    }
+
+    fn load_chess() -> Game {
+        parse_game_spec("./specs/chess.json")
+            .map(Game::from_spec)
+            .expect("Failed to load chess spec")
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    fn duck_position(game: &Game) -> Option<Position> {
+        game.state
+            .pieces
+            .iter()
+            .find(|(_, p)| p.is_neutral())
+            .map(|(pos, _)| pos.clone())
+    }
+
+    // -----------------------------------------------------------------------
+    // Placement phase
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_move_is_followed_by_placement() {
+        let mut game = load_duck_chess();
+        game.play(&step(vec![4, 1], vec![4, 3])).unwrap();
+
+        // Same player, now placing the duck on any of the 32 empty squares.
+        assert_eq!(
+            game.state.phase,
+            GamePhase::Placing {
+                piece_code: "DUCK".to_string()
+            }
+        );
+        assert_eq!(game.current_player(), "WHITE");
+        let plies = game.legal_plies();
+        assert_eq!(plies.len(), 32);
+        assert!(plies.iter().all(|ply| matches!(ply, Ply::Place { .. })));
+
+        // Moves cannot be started until the duck is placed.
+        assert!(matches!(
+            game.transition(GameTransition::CalculateMoves {
+                position: vec![3, 1]
+            }),
+            Err(GameError::InvalidGamePhase)
+        ));
+
+        game.play(&Ply::Place { to: vec![4, 5] }).unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.current_player(), "BLACK");
+
+        let duck = game.piece_at_position(&vec![4, 5]).unwrap();
+        assert_eq!(duck.player, NEUTRAL);
+
+        let record = game.history().last().unwrap();
+        assert_eq!(record.action, PLACE);
+        assert!(record.from.is_empty());
+    }
+
+    #[test]
+    fn test_duck_must_move_to_another_square() {
+        let mut game = load_duck_chess();
+        game.play(&step(vec![4, 1], vec![4, 3])).unwrap();
+        game.play(&Ply::Place { to: vec![4, 5] }).unwrap();
+        game.play(&step(vec![3, 6], vec![3, 4])).unwrap();
+
+        assert!(!game.legal_plies().contains(&Ply::Place { to: vec![4, 5] }));
+        assert!(game
+            .transition(GameTransition::Place {
+                position: vec![4, 5]
+            })
+            .is_err());
+
+        // A rejected square keeps the placement pending.
+        assert!(matches!(game.state.phase, GamePhase::Placing { .. }));
+
+        game.play(&Ply::Place { to: vec![2, 2] }).unwrap();
+        assert_eq!(duck_position(&game), Some(vec![2, 2]));
+        assert!(game.piece_at_position(&vec![4, 5]).is_none());
+        assert_eq!(game.history().last().unwrap().from, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_placement_ply_round_trips_through_json() {
+        let ply: Ply = serde_json::from_str(r#"{ "to": [3, 4] }"#).unwrap();
+        assert_eq!(ply, Ply::Place { to: vec![3, 4] });
+        assert_eq!(serde_json::to_string(&ply).unwrap(), r#"{"to":[3,4]}"#);
+    }
+
+    // -----------------------------------------------------------------------
+    // The duck blocks and cannot be taken
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_duck_blocks_lines_and_cannot_be_captured() {
+        let mut game = load_duck_chess();
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![4, 7], "KING", "BLACK");
+        game.state
+            .pieces
+            .insert(vec![0, 3], Piece::neutral("DUCK".to_string()));
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![0, 0],
+        })
+        .unwrap();
+        let moves = game.state.available_moves.clone().unwrap();
+        assert!(moves.contains_key(&vec![0, 2]));
+        assert!(!moves.contains_key(&vec![0, 3]));
+        assert!(!moves.contains_key(&vec![0, 4]));
+
+        // Nobody attacks the duck's square, and it cannot be selected.
+        assert!(!game.attacked_by("WHITE").contains(&vec![0, 3]));
+        assert!(matches!(
+            game.transition(GameTransition::CalculateMoves {
+                position: vec![0, 3]
+            }),
+            Err(GameError::InvalidPlayer)
+        ));
+    }
+
+    #[test]
+    fn test_king_capture_wins() {
+        let mut game = load_duck_chess();
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![0, 7], "KING", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![0, 7])).unwrap();
+        game.play(&Ply::Place { to: vec![3, 3] }).unwrap();
+
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_stalemated_player_wins() {
+        let mut game = load_duck_chess();
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "KING", "WHITE");
+        insert(&mut game, vec![1, 0], "PAWN", "WHITE");
+        insert(&mut game, vec![0, 1], "PAWN", "WHITE");
+        insert(&mut game, vec![1, 1], "PAWN", "WHITE");
+        insert(&mut game, vec![0, 2], "PAWN", "WHITE");
+        insert(&mut game, vec![0, 3], "PAWN", "BLACK");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+        game.state.current_turn = 1;
+
+        // The duck blocks the last white pawn that could still move.
+        game.play(&step(vec![7, 7], vec![7, 6])).unwrap();
+        game.play(&Ply::Place { to: vec![1, 2] }).unwrap();
+
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Neutral pieces in material accounting
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_neutral_pieces_ignored_by_insufficient_material() {
+        let mut game = load_chess();
+        game.state.pieces.retain(|_, p| p.code == "KING");
+        game.state
+            .pieces
+            .insert(vec![3, 3], Piece::neutral("DUCK".to_string()));
+
+        game.play(&step(vec![4, 0], vec![4, 1])).unwrap();
+
+        assert_eq!(game.state.phase, GamePhase::GameOver { winner: None });
+    }
+}
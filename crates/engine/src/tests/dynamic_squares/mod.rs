@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GameTransition, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    fn load_game() -> Game {
+        let mut game = parse_game_spec("./src/tests/dynamic_squares/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load dynamic squares test spec");
+        game.state.pieces.clear();
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn targets(game: &mut Game, pos: Position) -> Vec<Position> {
+        game.transition(GameTransition::CalculateMoves { position: pos })
+            .unwrap();
+        let mut targets: Vec<Position> = game
+            .state
+            .available_moves
+            .as_ref()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        targets.sort();
+        game.clear_moves();
+        targets
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Initial state
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_spec_disabled_positions_start_in_state() {
+        let mut game = load_game();
+        assert!(game.state.disabled_positions.contains(&vec![2, 2]));
+        assert_eq!(game.all_positions().len(), 24);
+
+        // The rook stops in front of the disabled square.
+        insert(&mut game, vec![2, 0], "ROOK", "WHITE");
+        let targets = targets(&mut game, vec![2, 0]);
+        assert!(targets.contains(&vec![2, 1]));
+        assert!(!targets.contains(&vec![2, 2]));
+        assert!(!targets.contains(&vec![2, 3]));
+    }
+
+    // -----------------------------------------------------------------------
+    // DISABLE / ENABLE side effects
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_disable_relative_square_during_play() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "WALKER", "WHITE");
+        insert(&mut game, vec![4, 0], "ROOK", "BLACK");
+
+        // The walker's floor collapses behind it.
+        game.play(&step(vec![0, 0], vec![1, 0])).unwrap();
+        assert!(game.state.disabled_positions.contains(&vec![0, 0]));
+        assert!(game.piece_at_position(&vec![0, 0]).is_none());
+        assert_eq!(game.all_positions().len(), 23);
+
+        // The rook can reach the walker but no longer the collapsed square.
+        let targets = targets(&mut game, vec![4, 0]);
+        assert!(targets.contains(&vec![1, 0]));
+        assert!(!targets.contains(&vec![0, 0]));
+    }
+
+    #[test]
+    fn test_disable_removes_piece_on_square() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "WALKER", "WHITE");
+        insert(&mut game, vec![0, 2], "ROOK", "BLACK");
+        insert(&mut game, vec![4, 4], "ROOK", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![0, 1])).unwrap();
+        assert!(game.state.disabled_positions.contains(&vec![0, 2]));
+        assert!(game.piece_at_position(&vec![0, 2]).is_none());
+    }
+
+    #[test]
+    fn test_enable_absolute_square() {
+        let mut game = load_game();
+        insert(&mut game, vec![4, 0], "MASON", "WHITE");
+        insert(&mut game, vec![2, 4], "ROOK", "BLACK");
+
+        game.play(&step(vec![4, 0], vec![4, 1])).unwrap();
+        assert!(game.state.disabled_positions.is_empty());
+
+        // The rook now slides through the centre.
+        let targets = targets(&mut game, vec![2, 4]);
+        assert!(targets.contains(&vec![2, 2]));
+        assert!(targets.contains(&vec![2, 0]));
+    }
+
+    #[test]
+    fn test_legality_sees_squares_the_move_enables() {
+        let mut game = load_game();
+        game.leader = vec!["WALKER".to_string()];
+        insert(&mut game, vec![2, 0], "WALKER", "WHITE");
+        insert(&mut game, vec![4, 0], "MASON", "WHITE");
+        insert(&mut game, vec![2, 4], "ROOK", "BLACK");
+
+        // Re-enabling the centre would open the rook's file onto the leader.
+        let plies = game.legal_plies();
+        assert!(!plies.contains(&step(vec![4, 0], vec![4, 1])));
+        assert!(plies.contains(&step(vec![2, 0], vec![3, 0])));
+    }
+
+    // -----------------------------------------------------------------------
+    // Serialization and repetition keys
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_disabled_squares_survive_save_and_restore() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "WALKER", "WHITE");
+        insert(&mut game, vec![4, 4], "ROOK", "BLACK");
+        game.play(&step(vec![0, 0], vec![1, 0])).unwrap();
+
+        let json = game.save_state().unwrap();
+        let mut restored = load_game();
+        restored.restore_state(&json).unwrap();
+
+        assert_eq!(
+            restored.state.disabled_positions,
+            game.state.disabled_positions
+        );
+        assert_eq!(restored.all_positions().len(), 23);
+    }
+
+    #[test]
+    fn test_state_saved_without_disabled_squares_uses_the_spec() {
+        let mut game = load_game();
+        insert(&mut game, vec![4, 4], "ROOK", "BLACK");
+
+        // A state saved before disabled squares were tracked has no such field.
+        let mut saved: serde_json::Value =
+            serde_json::from_str(&game.save_state().unwrap()).unwrap();
+        saved.as_object_mut().unwrap().remove("disabled_positions");
+
+        let mut restored = load_game();
+        restored.state.disabled_positions.clear();
+        restored.restore_state(&saved.to_string()).unwrap();
+
+        assert!(restored.state.disabled_positions.contains(&vec![2, 2]));
+        assert_eq!(restored.all_positions().len(), 24);
+    }
+
+    #[test]
+    fn test_disabled_squares_are_part_of_position_key() {
+        let mut intact = load_game();
+        let mut collapsed = load_game();
+        collapsed.state.disabled_positions.insert(vec![0, 0]);
+
+        // Same pieces and moves; only the disabled squares differ.
+        for game in [&mut intact, &mut collapsed] {
+            insert(game, vec![1, 0], "ROOK", "WHITE");
+            insert(game, vec![4, 4], "ROOK", "BLACK");
+            game.play(&step(vec![1, 0], vec![1, 1])).unwrap();
+        }
+
+        assert_ne!(
+            intact.state.position_hashes.last(),
+            collapsed.state.position_hashes.last()
+        );
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::{parse_game_spec, FacingLeadersSpec};
+
+    /// Empty 5×5 board with the default `facing_leaders` rule. RED holds a STONE in hand.
+    fn load_game() -> Game {
+        parse_game_spec("./src/tests/facing_leaders/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load facing leaders test spec")
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    fn drop_targets(game: &Game) -> Vec<Position> {
+        let mut targets: Vec<Position> = game
+            .legal_plies()
+            .into_iter()
+            .filter_map(|ply| match ply {
+                Ply::Drop { to, .. } => Some(to),
+                _ => None,
+            })
+            .collect();
+        targets.sort();
+        targets
+    }
+
+    // -----------------------------------------------------------------------
+    // Moves
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_blocker_cannot_leave_the_file_between_leaders() {
+        let mut game = load_game();
+        insert(&mut game, vec![2, 0], "GENERAL", "RED");
+        insert(&mut game, vec![2, 2], "GUARD", "RED");
+        insert(&mut game, vec![2, 4], "GENERAL", "BLACK");
+
+        let plies = game.legal_plies();
+        assert!(!plies.contains(&step(vec![2, 2], vec![1, 2])));
+        assert!(!plies.contains(&step(vec![2, 2], vec![3, 2])));
+        // Moving along the file keeps the line closed.
+        assert!(plies.contains(&step(vec![2, 2], vec![2, 3])));
+    }
+
+    #[test]
+    fn test_leader_cannot_step_onto_an_open_file() {
+        let mut game = load_game();
+        insert(&mut game, vec![1, 0], "GENERAL", "RED");
+        insert(&mut game, vec![2, 4], "GENERAL", "BLACK");
+
+        let plies = game.legal_plies();
+        assert!(!plies.contains(&step(vec![1, 0], vec![2, 0])));
+        assert!(plies.contains(&step(vec![1, 0], vec![0, 0])));
+    }
+
+    #[test]
+    fn test_any_piece_between_the_leaders_blocks_the_line() {
+        let mut game = load_game();
+        insert(&mut game, vec![1, 0], "GENERAL", "RED");
+        insert(&mut game, vec![2, 2], "GUARD", "BLACK");
+        insert(&mut game, vec![2, 4], "GENERAL", "BLACK");
+
+        assert!(game.legal_plies().contains(&step(vec![1, 0], vec![2, 0])));
+    }
+
+    // -----------------------------------------------------------------------
+    // Drops
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_drops_must_close_an_open_line() {
+        let mut game = load_game();
+        insert(&mut game, vec![2, 0], "GENERAL", "RED");
+        insert(&mut game, vec![2, 4], "GENERAL", "BLACK");
+
+        assert_eq!(
+            drop_targets(&game),
+            vec![vec![2, 1], vec![2, 2], vec![2, 3]]
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Configuration
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_rule_follows_the_configured_lines() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 2], "GENERAL", "RED");
+        insert(&mut game, vec![2, 2], "GUARD", "RED");
+        insert(&mut game, vec![4, 2], "GENERAL", "BLACK");
+        let advance = step(vec![2, 2], vec![2, 3]);
+        assert!(game.legal_plies().contains(&advance));
+
+        // Leaders facing along a rank, rather than a file.
+        game.facing_leaders = FacingLeadersSpec {
+            lines: vec![vec![1, 0]],
+        }
+        .steps(2);
+        assert!(!game.legal_plies().contains(&advance));
+
+        // Without the rule the line does not matter.
+        game.facing_leaders.clear();
+        assert!(game.legal_plies().contains(&advance));
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use crate::logic::{Game, GamePhase, GameTransition, Piece};
-    use crate::specs::parse_game_spec;
+    use crate::logic::{Game, GamePhase, GameTransition, Piece, Ply};
+    use crate::specs::{parse_game_spec, StalemateOutcome};
 
     fn load_game() -> Game {
         parse_game_spec("./src/tests/game_over/spec.json")
@@ -393,4 +393,127 @@ mod tests {
             "Without stalemate_loses flag, stalemate is still a draw"
         );
     }
+
+    // -------------------------------------------------------------------------
+    // Test 9 — stalemate_outcome=WIN: the stalemated player wins (antichess)
+    //
+    // Same stalemate position as test 1.
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_stalemate_outcome_win_awards_stalemated_player() {
+        let mut game = load_game();
+        game.stalemate_outcome = StalemateOutcome::Win;
+
+        insert(&mut game, vec![0, 0], "KING_PIECE", "WHITE");
+        insert(&mut game, vec![2, 1], "SLIDER", "BLACK");
+        insert(&mut game, vec![1, 4], "SLIDER", "BLACK");
+
+        game.state.current_turn = 1;
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![1, 4],
+        })
+        .unwrap();
+        game.transition(GameTransition::ExecuteMove {
+            position: vec![1, 3],
+        })
+        .unwrap();
+
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 10 — N-player stalemate as a draw: the stalemated player passes
+    //
+    // 3-player game (turn order: BLACK, WHITE, RED). BLACK stalemates WHITE
+    // with the pattern from test 1. WHITE keeps its turn slot but is skipped,
+    // and RED (who can move) plays next.
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_nplayer_stalemate_draw_skips_stalemated_player() {
+        let mut game = load_game_3player();
+
+        insert(&mut game, vec![0, 0], "KING_PIECE", "WHITE");
+        insert(&mut game, vec![2, 1], "SLIDER", "BLACK");
+        insert(&mut game, vec![1, 4], "SLIDER", "BLACK");
+        insert(&mut game, vec![7, 7], "KING_PIECE", "RED");
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![1, 4],
+        })
+        .unwrap();
+        game.transition(GameTransition::ExecuteMove {
+            position: vec![1, 3],
+        })
+        .unwrap();
+
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.turn_order.len(), 3, "WHITE keeps its turn slot");
+        assert_eq!(game.current_player(), "RED");
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 11 — N-player stalemate as a loss: the stalemated player is eliminated
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_nplayer_stalemate_loss_eliminates_stalemated_player() {
+        let mut game = load_game_3player();
+        game.stalemate_outcome = StalemateOutcome::Loss;
+
+        insert(&mut game, vec![0, 0], "KING_PIECE", "WHITE");
+        insert(&mut game, vec![2, 1], "SLIDER", "BLACK");
+        insert(&mut game, vec![1, 4], "SLIDER", "BLACK");
+        insert(&mut game, vec![7, 7], "KING_PIECE", "RED");
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![1, 4],
+        })
+        .unwrap();
+        game.transition(GameTransition::ExecuteMove {
+            position: vec![1, 3],
+        })
+        .unwrap();
+
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert!(game.turn_order.iter().all(|p| p != "WHITE"));
+        assert_eq!(game.current_player(), "RED");
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 12 — N-player: an eliminated player's king does not excuse check
+    //
+    // 4-player chess with SILVER already eliminated; its KING stays on the board.
+    // WHITE KING [0,0] is in check from the BLACK QUEEN [0,7]. Capturing SILVER's
+    // KING [5,2] with the WHITE QUEEN [5,5] leaves WHITE in check, so it is illegal.
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_nplayer_capturing_eliminated_king_does_not_escape_check() {
+        let mut game = parse_game_spec("./specs/4player_chess.json")
+            .map(Game::from_spec)
+            .expect("Failed to load 4-player chess spec");
+        game.state.pieces.clear();
+        game.turn_order.retain(|p| p != "SILVER");
+
+        insert(&mut game, vec![0, 0], "KING", "WHITE");
+        insert(&mut game, vec![5, 5], "QUEEN", "WHITE");
+        insert(&mut game, vec![0, 7], "QUEEN", "BLACK");
+        insert(&mut game, vec![5, 2], "KING", "SILVER");
+
+        assert!(game.leader_in_check());
+        let capture = Ply::Move {
+            from: vec![5, 5],
+            to: vec![5, 2],
+            promotion: None,
+        };
+        assert!(!game.legal_plies().contains(&capture));
+    }
 }
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GameTransition, Piece};
+    use crate::shared::{apply_direction, hex_rotation, identity_direction, HEX_ORTHOGONALS};
+    use crate::specs::parse_game_spec;
+
+    fn load_glinski() -> Game {
+        parse_game_spec("./specs/glinski.json")
+            .map(Game::from_spec)
+            .expect("Failed to load glinski spec")
+    }
+
+    fn insert(game: &mut Game, pos: Vec<u8>, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn move_count(game: &mut Game, pos: Vec<u8>) -> usize {
+        game.transition(GameTransition::CalculateMoves { position: pos })
+            .unwrap();
+        game.state.available_moves.as_ref().unwrap().len()
+    }
+
+    // -----------------------------------------------------------------------
+    // Board shape
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_glinski_board_has_91_cells() {
+        let game = load_glinski();
+        let cells = game.all_positions();
+        assert_eq!(cells.len(), 91);
+
+        // Corners of the bounding rhombus are cut off.
+        assert!(!cells.contains(&vec![0, 0]));
+        assert!(!cells.contains(&vec![10, 10]));
+        assert!(cells.contains(&vec![0, 5]));
+        assert!(cells.contains(&vec![10, 5]));
+    }
+
+    #[test]
+    fn test_hex_rotation() {
+        assert_eq!(hex_rotation(0), identity_direction(2));
+        assert_eq!(hex_rotation(6), hex_rotation(0));
+        assert_eq!(hex_rotation(3), vec![vec![-1, 0], vec![0, -1]]);
+
+        // One sixth turns each neighbour step into the next one, counter-clockwise.
+        for (i, step) in HEX_ORTHOGONALS.iter().enumerate() {
+            let next = HEX_ORTHOGONALS[(i + 5) % 6];
+            assert_eq!(
+                apply_direction(&hex_rotation(1), &step.to_vec()),
+                next.to_vec()
+            );
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Piece movement from the centre of an empty board
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_rook_bishop_knight_from_centre() {
+        let mut game = load_glinski();
+
+        for (code, expected) in [("ROOK", 30), ("BISHOP", 12), ("KNIGHT", 12), ("QUEEN", 42)] {
+            game.state.pieces.clear();
+            insert(&mut game, vec![5, 5], code, "WHITE");
+            assert_eq!(move_count(&mut game, vec![5, 5]), expected, "{code}");
+        }
+    }
+
+    #[test]
+    fn test_diagonal_slides_through_shared_corners() {
+        let mut game = load_glinski();
+        game.state.pieces.clear();
+
+        // A hex diagonal has no cell in between, so pieces on the two neighbouring cells
+        // do not block it.
+        insert(&mut game, vec![5, 5], "BISHOP", "WHITE");
+        insert(&mut game, vec![6, 4], "PAWN", "WHITE");
+        insert(&mut game, vec![6, 5], "PAWN", "WHITE");
+        insert(&mut game, vec![7, 4], "PAWN", "BLACK");
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![5, 5],
+        })
+        .unwrap();
+        let moves = game.state.available_moves.as_ref().unwrap();
+        assert!(
+            moves.contains_key(&vec![7, 4]),
+            "bishop should capture on [7,4]"
+        );
+        assert!(
+            !moves.contains_key(&vec![9, 3]),
+            "capture should stop the slide"
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Pawns
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_black_pawn_moves_down_the_file() {
+        let mut game = load_glinski();
+        game.state.current_turn = 1;
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![4, 7],
+        })
+        .unwrap();
+        let moves = game.state.available_moves.as_ref().unwrap();
+        assert_eq!(moves.len(), 2);
+        assert!(moves.contains_key(&vec![4, 6]));
+        assert!(moves.contains_key(&vec![4, 5]));
+    }
+
+    #[test]
+    fn test_pawn_captures_on_forward_neighbours() {
+        let mut game = load_glinski();
+        game.state.pieces.clear();
+
+        insert(&mut game, vec![5, 4], "PAWN", "WHITE");
+        insert(&mut game, vec![6, 4], "KNIGHT", "BLACK");
+        insert(&mut game, vec![4, 5], "KNIGHT", "BLACK");
+        insert(&mut game, vec![5, 5], "KNIGHT", "BLACK");
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![5, 4],
+        })
+        .unwrap();
+        let moves = game.state.available_moves.as_ref().unwrap();
+        assert_eq!(moves.len(), 2);
+        assert!(moves.contains_key(&vec![6, 4]));
+        assert!(moves.contains_key(&vec![4, 5]));
+    }
+
+    #[test]
+    fn test_opening_move_count() {
+        let game = load_glinski();
+        assert_eq!(game.legal_plies().len(), 51);
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GameError, GameTransition, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::{parse_game_spec, MandatoryCaptureSpec};
+
+    fn load_game() -> Game {
+        let mut game = parse_game_spec("./src/tests/mandatory_capture/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load mandatory capture test spec");
+        game.state.pieces.clear();
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Captures are compulsory
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_quiet_moves_are_filtered_when_a_capture_exists() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![6, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 3], "STONE", "BLACK");
+
+        assert_eq!(game.required_captures(), Some(1));
+        assert_eq!(game.legal_plies(), vec![step(vec![0, 0], vec![0, 3])]);
+
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![0, 0],
+        })
+        .unwrap();
+        let moves = game.state.available_moves.clone().unwrap();
+        assert_eq!(moves.keys().collect::<Vec<_>>(), vec![&vec![0, 3]]);
+    }
+
+    #[test]
+    fn test_quiet_move_reports_capture_mandatory() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![6, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 3], "STONE", "BLACK");
+
+        // Another piece with no capture of its own.
+        assert!(matches!(
+            game.play(&step(vec![6, 0], vec![6, 1])),
+            Err(GameError::CaptureMandatory)
+        ));
+
+        // The capturing piece trying a quiet move.
+        assert!(matches!(
+            game.play(&step(vec![0, 0], vec![0, 1])),
+            Err(GameError::CaptureMandatory)
+        ));
+
+        // Squares the piece cannot reach at all are still plain invalid moves.
+        assert!(matches!(
+            game.play(&step(vec![0, 0], vec![1, 1])),
+            Err(GameError::InvalidMove)
+        ));
+    }
+
+    #[test]
+    fn test_side_effect_captures_count() {
+        let mut game = load_game();
+        insert(&mut game, vec![2, 0], "JUMPER", "WHITE");
+        insert(&mut game, vec![6, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![3, 1], "STONE", "BLACK");
+
+        // The jump is a `MOVE` that removes the stone through a side effect.
+        assert_eq!(game.legal_plies(), vec![step(vec![2, 0], vec![4, 2])]);
+    }
+
+    #[test]
+    fn test_any_capture_goes_without_maximum() {
+        let mut game = load_game();
+        insert(&mut game, vec![3, 0], "FORK", "WHITE");
+        insert(&mut game, vec![2, 0], "STONE", "BLACK");
+        insert(&mut game, vec![4, 0], "STONE", "BLACK");
+        insert(&mut game, vec![0, 6], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 3], "STONE", "BLACK");
+
+        // The fork takes two stones at once and the rook one: both remain legal.
+        assert_eq!(game.required_captures(), Some(1));
+        let mut plies = game.legal_plies();
+        plies.sort_by_key(|ply| format!("{ply:?}"));
+        assert_eq!(
+            plies,
+            vec![step(vec![0, 6], vec![0, 3]), step(vec![3, 0], vec![3, 1])]
+        );
+    }
+
+    #[test]
+    fn test_any_move_goes_without_captures() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![6, 6], "STONE", "BLACK");
+
+        assert_eq!(game.required_captures(), None);
+        assert_eq!(game.legal_plies().len(), 12);
+    }
+
+    #[test]
+    fn test_drops_are_blocked_by_available_capture() {
+        let mut game = load_game();
+        game.hand_enabled = true;
+        game.state
+            .hand
+            .entry("WHITE".to_string())
+            .or_default()
+            .insert("ROOK".to_string(), 1);
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 3], "STONE", "BLACK");
+
+        assert!(!game
+            .legal_plies()
+            .iter()
+            .any(|ply| matches!(ply, Ply::Drop { .. })));
+        assert!(matches!(
+            game.transition(GameTransition::CalculateDrops {
+                piece_code: "ROOK".to_string()
+            }),
+            Err(GameError::CaptureMandatory)
+        ));
+    }
+
+    // -----------------------------------------------------------------------
+    // Maximum capture
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_maximum_capture_counts_every_leg() {
+        let mut game = load_game();
+        game.mandatory_capture = Some(MandatoryCaptureSpec { maximum: true });
+        insert(&mut game, vec![0, 0], "JUMPER", "WHITE");
+        insert(&mut game, vec![6, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![1, 1], "STONE", "BLACK");
+        insert(&mut game, vec![3, 3], "STONE", "BLACK");
+        insert(&mut game, vec![6, 4], "STONE", "BLACK");
+
+        // The rook takes one stone; the jumper's chain takes two.
+        assert_eq!(game.required_captures(), Some(2));
+        assert_eq!(game.legal_plies(), vec![step(vec![0, 0], vec![2, 2])]);
+        assert!(matches!(
+            game.play(&step(vec![6, 0], vec![6, 4])),
+            Err(GameError::CaptureMandatory)
+        ));
+
+        game.mandatory_capture = Some(MandatoryCaptureSpec { maximum: false });
+        assert_eq!(game.legal_plies().len(), 2);
+    }
+
+    #[test]
+    fn test_maximum_capture_stops_at_looping_continuations() {
+        let mut game = load_game();
+        game.mandatory_capture = Some(MandatoryCaptureSpec { maximum: true });
+        // The shuttle's legs continue into each other, so it can go back and forth forever.
+        insert(&mut game, vec![3, 3], "SHUTTLE", "WHITE");
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 3], "STONE", "BLACK");
+
+        assert_eq!(game.required_captures(), Some(1));
+        assert_eq!(game.legal_plies(), vec![step(vec![0, 0], vec![0, 3])]);
+    }
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, Piece, Ply};
+    use crate::search::{MateResult, MateSolver};
+    use crate::specs::parse_game_spec;
+
+    fn load(path: &str) -> Game {
+        parse_game_spec(path)
+            .map(Game::from_spec)
+            .expect("Failed to load spec")
+    }
+
+    fn insert(game: &mut Game, pos: Vec<u8>, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn mv(from: [u8; 2], to: [u8; 2]) -> Ply {
+        Ply::Move {
+            from: from.to_vec(),
+            to: to.to_vec(),
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Mate in 1 — BLACK KING [7,7], WHITE KING [5,5], WHITE QUEEN [0,6].
+    // Queen to [6,6] is the only mate.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_finds_mate_in_one() {
+        let mut game = load("./specs/chess.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+        insert(&mut game, vec![5, 5], "KING", "WHITE");
+        insert(&mut game, vec![0, 6], "QUEEN", "WHITE");
+
+        assert_eq!(
+            MateSolver::new(1).solve(&game),
+            MateResult::Mate {
+                moves: 1,
+                line: vec![mv([0, 6], [6, 6])],
+            }
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Mate in 2 — BLACK KING [7,7], WHITE KING [5,5], WHITE ROOK [0,0].
+    // No mate in 1 (the king escapes to [7,6]). After KING [5,5]→[5,6] BLACK is
+    // forced to [7,6], and ROOK [0,0]→[7,0] mates along the edge file.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_finds_mate_in_two_with_main_line() {
+        let mut game = load("./specs/chess.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+        insert(&mut game, vec![5, 5], "KING", "WHITE");
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+
+        assert_eq!(MateSolver::new(1).solve(&game), MateResult::NoMate);
+
+        let MateResult::Mate { moves, line } = MateSolver::new(2).solve(&game) else {
+            panic!("Expected a mate in 2");
+        };
+        assert_eq!(moves, 2);
+        assert_eq!(line.len(), 3, "attacker, defence, attacker");
+
+        // Replaying the main line must end the game in WHITE's favour.
+        let mut replay = game.clone();
+        for ply in &line {
+            replay.play(ply).unwrap();
+        }
+        assert_eq!(
+            replay.state.phase,
+            crate::logic::GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // No forced win — kings and a distant pawn each.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_proves_no_mate_within_depth() {
+        let mut game = load("./specs/chess.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "KING", "WHITE");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+        insert(&mut game, vec![7, 1], "PAWN", "WHITE");
+        insert(&mut game, vec![0, 6], "PAWN", "BLACK");
+
+        assert_eq!(MateSolver::new(1).solve(&game), MateResult::NoMate);
+    }
+
+    // -----------------------------------------------------------------------
+    // King of the Hill — forced PIECE_IN_ZONE counts as a win.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_king_of_the_hill_zone_win() {
+        let mut game = load("./specs/king_of_the_hill.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![3, 2], "KING", "WHITE");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+
+        let MateResult::Mate { moves, line } = MateSolver::new(1).solve(&game) else {
+            panic!("Expected a forced zone win");
+        };
+        assert_eq!(moves, 1);
+        let Ply::Move { to, .. } = &line[0] else {
+            panic!("Expected a board move");
+        };
+        assert!(game.check_position_condition(to, &"CENTER".to_string()));
+    }
+
+    // -----------------------------------------------------------------------
+    // Three-check — with two checks already delivered, any check wins.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_three_check_third_check_wins() {
+        let mut game = load("./specs/three_check.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "KING", "WHITE");
+        insert(&mut game, vec![3, 3], "ROOK", "WHITE");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+        game.state.check_counts.insert("WHITE".to_string(), 2);
+
+        let MateResult::Mate { moves, line } = MateSolver::new(1).solve(&game) else {
+            panic!("Expected the third check to win");
+        };
+        assert_eq!(moves, 1);
+
+        let mut replay = game.clone();
+        replay.play(&line[0]).unwrap();
+        assert_eq!(replay.state.check_counts["WHITE"], 3);
+    }
+
+    // -----------------------------------------------------------------------
+    // Shogi — drop mate with a GOLD from hand.
+    //
+    // GOTE KING [4,8], SENTE PAWN [4,6] (guards [4,7]), SENTE KING [4,0].
+    // Dropping the GOLD on [4,7] covers every escape square and is protected.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_shogi_drop_mate() {
+        let mut game = load("./specs/shogi.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![4, 8], "KING", "GOTE");
+        insert(&mut game, vec![4, 6], "PAWN", "SENTE");
+        insert(&mut game, vec![4, 0], "KING", "SENTE");
+        game.state
+            .hand
+            .entry("SENTE".to_string())
+            .or_default()
+            .insert("GOLD".to_string(), 1);
+
+        assert_eq!(
+            MateSolver::new(1).solve(&game),
+            MateResult::Mate {
+                moves: 1,
+                line: vec![Ply::Drop {
+                    piece_code: "GOLD".to_string(),
+                    to: vec![4, 7],
+                }],
+            }
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Three players — only eliminating the defender wins.
+    //
+    // Order BLACK, WHITE, RED with EXTINCTION of SLIDER and no leaders. The BLACK
+    // SLIDER [0,0] can capture RED's only SLIDER [0,5], which eliminates RED but
+    // leaves WHITE, the defender, in the game.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_three_players_only_the_defender_elimination_wins() {
+        let mut game = load("./src/tests/game_over/spec_3player.json");
+        game.leader.clear();
+        let extinction = r#"{ "type": "EXTINCTION", "pieces": ["SLIDER"] }"#;
+        game.win_conditions = vec![serde_json::from_str(extinction).unwrap()];
+        insert(&mut game, vec![0, 0], "SLIDER", "BLACK");
+        insert(&mut game, vec![0, 5], "SLIDER", "RED");
+        insert(&mut game, vec![7, 7], "SLIDER", "WHITE");
+
+        assert_eq!(MateSolver::new(1).solve(&game), MateResult::NoMate);
+
+        // With WHITE's SLIDER in reach, capturing it is the win.
+        game.state.pieces.remove(&vec![7, 7]);
+        insert(&mut game, vec![5, 0], "SLIDER", "WHITE");
+        assert_eq!(
+            MateSolver::new(1).solve(&game),
+            MateResult::Mate {
+                moves: 1,
+                line: vec![mv([0, 0], [5, 0])],
+            }
+        );
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::logic::{Game, GamePhase, Piece, Ply};
+    use crate::search::{Mcts, MctsBudget, MctsConfig};
+    use crate::specs::parse_game_spec;
+
+    fn load(path: &str) -> Game {
+        parse_game_spec(path)
+            .map(Game::from_spec)
+            .expect("Failed to load spec")
+    }
+
+    fn insert(game: &mut Game, pos: Vec<u8>, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn mcts(iterations: u32, max_playout_plies: u32) -> Mcts {
+        Mcts::new(MctsConfig {
+            budget: MctsBudget::Iterations(iterations),
+            max_playout_plies,
+            ..MctsConfig::default()
+        })
+    }
+
+    // -----------------------------------------------------------------------
+    // legal_plies: self-check filtering and promotion expansion
+    //
+    // WHITE KING [4,0], WHITE ROOK [4,1] pinned by BLACK ROOK [4,7].
+    // The pinned rook may only slide along the file; sideways moves are illegal.
+    // A WHITE PAWN on [0,6] yields one ply per promotion option.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_legal_plies_filters_pins_and_expands_promotions() {
+        let mut game = load("./specs/chess.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![4, 1], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 6], "PAWN", "WHITE");
+        insert(&mut game, vec![4, 7], "ROOK", "BLACK");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+
+        let plies = game.legal_plies();
+
+        assert!(!plies.contains(&Ply::Move {
+            from: vec![4, 1],
+            to: vec![3, 1],
+            promotion: None,
+        }));
+        assert!(plies.contains(&Ply::Move {
+            from: vec![4, 1],
+            to: vec![4, 7],
+            promotion: None,
+        }));
+
+        let promotions: Vec<&Ply> = plies
+            .iter()
+            .filter(|p| matches!(p, Ply::Move { from, .. } if *from == vec![0u8, 6u8]))
+            .collect();
+        assert_eq!(promotions.len(), 4, "one ply per promotion option");
+    }
+
+    // -----------------------------------------------------------------------
+    // Game::play drives the full transition flow, including promotion.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_play_resolves_promotion() {
+        let mut game = load("./specs/chess.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![0, 6], "PAWN", "WHITE");
+        insert(&mut game, vec![7, 4], "KING", "BLACK");
+
+        game.play(&Ply::Move {
+            from: vec![0, 6],
+            to: vec![0, 7],
+            promotion: Some("ROOK".to_string()),
+        })
+        .unwrap();
+
+        assert_eq!(game.state.pieces[&vec![0u8, 7u8]].code, "ROOK");
+        assert_eq!(game.current_player(), "BLACK");
+        assert_eq!(game.state.phase, GamePhase::Idle);
+    }
+
+    // -----------------------------------------------------------------------
+    // MCTS finds a mate in one
+    //
+    // BLACK KING [7,7], WHITE KING [5,5], WHITE QUEEN [0,6].
+    // Queen to [6,6] is mate (protected by the king); nothing else wins at once.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_mcts_finds_mate_in_one() {
+        let mut game = load("./specs/chess.json");
+        game.state.pieces.clear();
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+        insert(&mut game, vec![5, 5], "KING", "WHITE");
+        insert(&mut game, vec![0, 6], "QUEEN", "WHITE");
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let result = mcts(100, 4).search(&game, &mut rng);
+
+        assert_eq!(
+            result.best,
+            Some(Ply::Move {
+                from: vec![0, 6],
+                to: vec![6, 6],
+                promotion: None,
+            })
+        );
+        assert_eq!(result.moves[0].mean_reward, 1.0);
+    }
+
+    // -----------------------------------------------------------------------
+    // Same seed → same search.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_mcts_is_reproducible_with_same_seed() {
+        let game = load("./specs/ataxx.json");
+
+        let first = mcts(60, 6).search(&game, &mut StdRng::seed_from_u64(42));
+        let second = mcts(60, 6).search(&game, &mut StdRng::seed_from_u64(42));
+
+        assert_eq!(first.best, second.best);
+        let visits = |r: &crate::search::MctsResult| {
+            r.moves
+                .iter()
+                .map(|m| (m.ply.clone(), m.visits))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(visits(&first), visits(&second));
+        assert_eq!(first.iterations, 60);
+    }
+
+    // -----------------------------------------------------------------------
+    // N-player: works on the 4-player chess spec.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_mcts_four_player_returns_legal_ply() {
+        let game = load("./specs/4player_chess.json");
+
+        let result = mcts(40, 8).search(&game, &mut StdRng::seed_from_u64(1));
+
+        let best = result.best.expect("a ply should be recommended");
+        assert!(game.legal_plies().contains(&best));
+        assert!(result
+            .moves
+            .iter()
+            .all(|m| (0.0..=1.0).contains(&m.mean_reward)));
+    }
+
+    // -----------------------------------------------------------------------
+    // Time budget stops the search.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_mcts_time_budget_terminates() {
+        let game = load("./specs/ataxx.json");
+        let mcts = Mcts::new(MctsConfig {
+            budget: MctsBudget::Time(Duration::from_millis(50)),
+            max_playout_plies: 4,
+            ..MctsConfig::default()
+        });
+
+        let result = mcts.search(&game, &mut StdRng::seed_from_u64(3));
+
+        assert!(result.iterations > 0);
+        assert!(result.best.is_some());
+    }
+
+    // -----------------------------------------------------------------------
+    // No recommendation once the game is over.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_mcts_returns_none_when_game_over() {
+        let mut game = load("./specs/chess.json");
+        game.state.phase = GamePhase::GameOver {
+            winner: Some("WHITE".to_string()),
+        };
+
+        let result = mcts(10, 4).search(&game, &mut StdRng::seed_from_u64(0));
+
+        assert!(result.best.is_none());
+        assert_eq!(result.iterations, 0);
+    }
+}
@@ -1,19 +1,41 @@
+mod asymmetric;
 mod ataxx;
+mod atomic;
 mod capablanca;
 mod chaturanga;
 mod attack_map;
 mod castling;
+mod combinators;
+mod conditional_side_effects;
 mod conditions;
+mod counters;
 mod crazyhouse;
+mod custodial;
 mod direction;
 mod draw_conditions;
+mod duck_chess;
+mod facing_leaders;
+mod dynamic_squares;
 mod game_over;
+mod hex;
+mod mandatory_capture;
+mod mate_solver;
+mod mcts;
 mod move_history;
+mod multi_leg;
 mod new_conditions;
 mod not_attacked;
+mod puzzle;
+mod raumschach;
+mod regions;
+mod reversi;
 mod serialization;
+mod shooting;
 mod shogi;
 mod side_effects;
+mod sliding;
 mod state_flags;
 mod transformation;
+mod variables;
 mod win_conditions;
+mod wrap;
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GameError, GamePhase, GameTransition, Piece, Ply};
+    use crate::shared::{Position, CAPTURE, MOVE};
+    use crate::specs::parse_game_spec;
+
+    fn load_game() -> Game {
+        let mut game = parse_game_spec("./src/tests/multi_leg/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load multi-leg test spec");
+        game.state.pieces.clear();
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Mandatory chains
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_capture_chain_continues_with_same_piece() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "JUMPER", "WHITE");
+        insert(&mut game, vec![6, 0], "JUMPER", "WHITE");
+        insert(&mut game, vec![1, 1], "STONE", "BLACK");
+        insert(&mut game, vec![3, 3], "STONE", "BLACK");
+        insert(&mut game, vec![6, 6], "KING", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![2, 2])).unwrap();
+        assert!(game.piece_at_position(&vec![1, 1]).is_none());
+        assert_eq!(
+            game.state.phase,
+            GamePhase::Continuing {
+                position: vec![2, 2],
+                move_ids: vec![1, 2],
+                mandatory: true,
+            }
+        );
+        assert_eq!(game.current_player(), "WHITE");
+
+        // Only the jumping piece may go on, and it cannot stop.
+        assert_eq!(game.legal_plies(), vec![step(vec![2, 2], vec![4, 4])]);
+        assert!(matches!(
+            game.transition(GameTransition::CalculateMoves {
+                position: vec![6, 0]
+            }),
+            Err(GameError::InvalidMove)
+        ));
+
+        game.play(&step(vec![2, 2], vec![4, 4])).unwrap();
+        assert!(game.piece_at_position(&vec![3, 3]).is_none());
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.current_player(), "BLACK");
+    }
+
+    #[test]
+    fn test_chain_is_a_single_history_record() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "JUMPER", "WHITE");
+        insert(&mut game, vec![1, 1], "STONE", "BLACK");
+        insert(&mut game, vec![3, 3], "STONE", "BLACK");
+        insert(&mut game, vec![6, 6], "KING", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![2, 2])).unwrap();
+        game.play(&step(vec![2, 2], vec![4, 4])).unwrap();
+
+        assert_eq!(game.history().len(), 1);
+        let record = game.history().last().unwrap();
+        assert_eq!(record.from, vec![0, 0]);
+        assert_eq!(record.to, vec![4, 4]);
+        assert_eq!(record.action, CAPTURE);
+    }
+
+    #[test]
+    fn test_mandatory_chain_cannot_be_stopped() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "JUMPER", "WHITE");
+        insert(&mut game, vec![1, 1], "STONE", "BLACK");
+        insert(&mut game, vec![3, 3], "STONE", "BLACK");
+        insert(&mut game, vec![6, 6], "KING", "BLACK");
+        game.play(&step(vec![0, 0], vec![2, 2])).unwrap();
+
+        assert!(matches!(
+            game.play(&Ply::Stop { at: vec![2, 2] }),
+            Err(GameError::InvalidGamePhase)
+        ));
+
+        // A rejected target keeps the chain going.
+        assert!(game.play(&step(vec![2, 2], vec![2, 3])).is_err());
+        assert!(matches!(game.state.phase, GamePhase::Continuing { .. }));
+    }
+
+    // -----------------------------------------------------------------------
+    // Optional chains
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_optional_chain_can_stop() {
+        let mut game = load_game();
+        insert(&mut game, vec![3, 0], "LION", "WHITE");
+        insert(&mut game, vec![6, 6], "KING", "BLACK");
+
+        game.play(&step(vec![3, 0], vec![3, 1])).unwrap();
+        assert_eq!(
+            game.legal_plies(),
+            vec![step(vec![3, 1], vec![4, 1]), Ply::Stop { at: vec![3, 1] }]
+        );
+
+        game.play(&Ply::Stop { at: vec![3, 1] }).unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.current_player(), "BLACK");
+
+        let record = game.history().last().unwrap();
+        assert_eq!(record.to, vec![3, 1]);
+        assert_eq!(record.action, MOVE);
+    }
+
+    #[test]
+    fn test_optional_chain_second_leg_captures() {
+        let mut game = load_game();
+        insert(&mut game, vec![3, 0], "LION", "WHITE");
+        insert(&mut game, vec![4, 1], "STONE", "BLACK");
+        insert(&mut game, vec![6, 6], "KING", "BLACK");
+
+        game.play(&step(vec![3, 0], vec![3, 1])).unwrap();
+        game.play(&step(vec![3, 1], vec![4, 1])).unwrap();
+
+        assert_eq!(game.current_player(), "BLACK");
+        assert_eq!(game.piece_at_position(&vec![4, 1]).unwrap().code, "LION");
+
+        let record = game.history().last().unwrap();
+        assert_eq!(record.from, vec![3, 0]);
+        assert_eq!(record.to, vec![4, 1]);
+        assert_eq!(record.action, CAPTURE);
+    }
+
+    #[test]
+    fn test_chain_ends_without_continuation_moves() {
+        let mut game = load_game();
+        insert(&mut game, vec![6, 0], "LION", "WHITE");
+        insert(&mut game, vec![0, 6], "KING", "BLACK");
+
+        // Nothing lies beyond the edge, so the turn ends straight away.
+        game.play(&step(vec![6, 0], vec![6, 1])).unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.current_player(), "BLACK");
+    }
+
+    // -----------------------------------------------------------------------
+    // Legality
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_continuation_cannot_leave_leader_in_check() {
+        let mut game = load_game();
+        game.leader = vec!["LION".to_string()];
+        insert(&mut game, vec![3, 0], "LION", "WHITE");
+        insert(&mut game, vec![4, 2], "KING", "BLACK");
+
+        // The only continuation steps onto a square the black king attacks.
+        assert!(game.attacked_by("BLACK").contains(&vec![4, 1]));
+        game.play(&step(vec![3, 0], vec![3, 1])).unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.current_player(), "BLACK");
+    }
+
+    #[test]
+    fn test_stop_ply_round_trips_through_json() {
+        let ply: Ply = serde_json::from_str(r#"{ "at": [2, 4] }"#).unwrap();
+        assert_eq!(ply, Ply::Stop { at: vec![2, 4] });
+        assert_eq!(serde_json::to_string(&ply).unwrap(), r#"{"at":[2,4]}"#);
+    }
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::logic::{Game, GamePhase, Ply, Puzzle, PuzzleError, PuzzleStatus};
+    use crate::shared::{LINE, MATE};
+    use crate::specs::{parse_game_spec, parse_puzzles, PiecePositionSpec, PuzzleSpec};
+
+    fn load(spec: &PuzzleSpec) -> Puzzle {
+        let game = parse_game_spec(format!("./specs/{}.json", spec.spec))
+            .map(Game::from_spec)
+            .expect("Failed to load spec");
+        Puzzle::new(spec.clone(), game).expect("Failed to set up puzzle")
+    }
+
+    fn mv(from: [u8; 2], to: [u8; 2]) -> Ply {
+        Ply::Move {
+            from: from.to_vec(),
+            to: to.to_vec(),
+            promotion: None,
+        }
+    }
+
+    fn pieces(entries: &[(&str, [u8; 2])]) -> Vec<PiecePositionSpec> {
+        entries
+            .iter()
+            .map(|(piece, pos)| PiecePositionSpec {
+                piece: piece.to_string(),
+                positions: vec![pos.to_vec()],
+            })
+            .collect()
+    }
+
+    /// WHITE KING [5,5], QUEEN [0,6], ROOK [1,0] vs BLACK KING [7,7]: both
+    /// QUEEN → [6,6] and ROOK → [1,7] mate at once. The solution lists the queen.
+    fn two_mates(goal: &str) -> Puzzle {
+        load(&PuzzleSpec {
+            name: String::new(),
+            spec: "chess".to_string(),
+            to_move: "WHITE".to_string(),
+            goal: goal.to_string(),
+            position: HashMap::from([
+                (
+                    "WHITE".to_string(),
+                    pieces(&[("KING", [5, 5]), ("QUEEN", [0, 6]), ("ROOK", [1, 0])]),
+                ),
+                ("BLACK".to_string(), pieces(&[("KING", [7, 7])])),
+            ]),
+            hand: HashMap::new(),
+            solution: vec![mv([0, 6], [6, 6])],
+        })
+    }
+
+    // -----------------------------------------------------------------------
+    // Every bundled puzzle sets up and is solved by following its own line.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_bundled_puzzles_solve_with_their_lines() {
+        let puzzles = parse_puzzles("./puzzles/basic.json").expect("Failed to load puzzles");
+        assert!(!puzzles.is_empty());
+
+        for spec in &puzzles {
+            let mut puzzle = load(spec);
+            while *puzzle.status() == PuzzleStatus::InProgress {
+                let ply = puzzle.hint().cloned().expect("solution ran out");
+                puzzle.submit(&ply).unwrap();
+            }
+            assert_eq!(*puzzle.status(), PuzzleStatus::Solved, "{}", spec.name);
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Opponent replies are played automatically between the user's moves.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_opponent_reply_is_auto_played() {
+        let puzzles = parse_puzzles("./puzzles/basic.json").unwrap();
+        let mut puzzle = load(&puzzles[0]);
+
+        assert_eq!(
+            puzzle.submit(&mv([5, 5], [5, 6])).unwrap(),
+            PuzzleStatus::InProgress
+        );
+        assert_eq!(puzzle.game.current_player(), "WHITE");
+        assert!(puzzle.game.state.pieces.contains_key(&vec![7u8, 6u8]));
+
+        assert_eq!(
+            puzzle.submit(&mv([0, 0], [7, 0])).unwrap(),
+            PuzzleStatus::Solved
+        );
+        assert_eq!(
+            puzzle.game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // A legal move that is not in the solution fails the puzzle.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_wrong_move_fails() {
+        let mut puzzle = two_mates(LINE);
+
+        assert_eq!(
+            puzzle.submit(&mv([1, 0], [1, 1])).unwrap(),
+            PuzzleStatus::Failed
+        );
+        assert!(matches!(
+            puzzle.submit(&mv([0, 6], [6, 6])),
+            Err(PuzzleError::NotInProgress)
+        ));
+    }
+
+    // -----------------------------------------------------------------------
+    // "LINE" rejects an alternative mate; "MATE" accepts it.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_alternative_mate_depends_on_goal() {
+        let mut strict = two_mates(LINE);
+        assert_eq!(
+            strict.submit(&mv([1, 0], [1, 7])).unwrap(),
+            PuzzleStatus::Failed
+        );
+
+        let mut lenient = two_mates(MATE);
+        assert_eq!(
+            lenient.submit(&mv([1, 0], [1, 7])).unwrap(),
+            PuzzleStatus::Solved
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // "MATE" accepts a different route to a longer win and follows the
+    // solver's line from there.
+    //
+    // King of the Hill: WHITE KING [3,1] reaches the centre in two moves via
+    // [3,2] (the listed line) or via [4,2].
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_alternative_route_rewrites_solution() {
+        let mut puzzle = load(&PuzzleSpec {
+            name: String::new(),
+            spec: "king_of_the_hill".to_string(),
+            to_move: "WHITE".to_string(),
+            goal: MATE.to_string(),
+            position: HashMap::from([
+                ("WHITE".to_string(), pieces(&[("KING", [3, 1])])),
+                ("BLACK".to_string(), pieces(&[("KING", [7, 7])])),
+            ]),
+            hand: HashMap::new(),
+            solution: vec![mv([3, 1], [3, 2]), mv([7, 7], [7, 6]), mv([3, 2], [3, 3])],
+        });
+
+        assert_eq!(
+            puzzle.submit(&mv([3, 1], [4, 2])).unwrap(),
+            PuzzleStatus::InProgress
+        );
+        let next = puzzle.hint().cloned().expect("a continuation");
+        assert!(matches!(&next, Ply::Move { from, .. } if *from == vec![4u8, 2u8]));
+        assert_eq!(puzzle.submit(&next).unwrap(), PuzzleStatus::Solved);
+    }
+
+    // -----------------------------------------------------------------------
+    // Illegal moves are rejected without ending the puzzle.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_illegal_move_is_rejected() {
+        let mut puzzle = two_mates(LINE);
+
+        assert!(matches!(
+            puzzle.submit(&mv([0, 6], [1, 0])),
+            Err(PuzzleError::IllegalMove)
+        ));
+        assert_eq!(*puzzle.status(), PuzzleStatus::InProgress);
+        assert_eq!(
+            puzzle.submit(&mv([0, 6], [6, 6])).unwrap(),
+            PuzzleStatus::Solved
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // A solution that is illegal from the position is reported on setup.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_invalid_solution_is_rejected() {
+        let puzzles = parse_puzzles("./puzzles/basic.json").unwrap();
+        let mut spec = puzzles[0].clone();
+        spec.solution[2] = mv([0, 0], [1, 1]);
+
+        let game = parse_game_spec("./specs/chess.json")
+            .map(Game::from_spec)
+            .unwrap();
+        assert!(matches!(
+            Puzzle::new(spec, game),
+            Err(PuzzleError::InvalidSolution(2))
+        ));
+    }
+}
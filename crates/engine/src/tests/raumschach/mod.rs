@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::logic::{Game, GamePhase, GameTransition, Piece};
+    use crate::specs::parse_game_spec;
+
+    fn load_raumschach() -> Game {
+        parse_game_spec("./specs/raumschach.json")
+            .map(Game::from_spec)
+            .expect("Failed to load raumschach spec")
+    }
+
+    fn insert(game: &mut Game, pos: Vec<u8>, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn targets(game: &mut Game, pos: Vec<u8>) -> Vec<Vec<u8>> {
+        game.transition(GameTransition::CalculateMoves { position: pos })
+            .unwrap();
+        let mut targets: Vec<Vec<u8>> = game
+            .state
+            .available_moves
+            .as_ref()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        targets.sort();
+        targets
+    }
+
+    // -----------------------------------------------------------------------
+    // Board shape
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_raumschach_board_has_125_cells() {
+        let game = load_raumschach();
+        assert_eq!(game.all_positions().len(), 125);
+        assert_eq!(game.state.pieces.len(), 40);
+    }
+
+    // -----------------------------------------------------------------------
+    // Piece movement from the centre of an empty cube
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_pieces_from_centre() {
+        let mut game = load_raumschach();
+
+        for (code, expected) in [
+            ("ROOK", 12),
+            ("BISHOP", 24),
+            ("UNICORN", 16),
+            ("QUEEN", 52),
+            ("KNIGHT", 24),
+            ("KING", 26),
+        ] {
+            game.state.pieces.clear();
+            insert(&mut game, vec![2, 2, 2], code, "WHITE");
+            assert_eq!(targets(&mut game, vec![2, 2, 2]).len(), expected, "{code}");
+        }
+    }
+
+    #[test]
+    fn test_unicorn_only_moves_through_corners() {
+        let mut game = load_raumschach();
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0, 0], "UNICORN", "WHITE");
+        insert(&mut game, vec![3, 3, 3], "PAWN", "BLACK");
+
+        assert_eq!(
+            targets(&mut game, vec![0, 0, 0]),
+            vec![vec![1, 1, 1], vec![2, 2, 2], vec![3, 3, 3]]
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Pawns move forward and upward for WHITE, backward and downward for BLACK
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_pawn_directions() {
+        let mut game = load_raumschach();
+        game.state.pieces.clear();
+        insert(&mut game, vec![2, 1, 1], "PAWN", "WHITE");
+        insert(&mut game, vec![2, 3, 3], "PAWN", "BLACK");
+
+        // Enemies on two of the capture cells.
+        insert(&mut game, vec![1, 2, 1], "KNIGHT", "BLACK");
+        insert(&mut game, vec![2, 2, 2], "KNIGHT", "BLACK");
+
+        assert_eq!(
+            targets(&mut game, vec![2, 1, 1]),
+            vec![vec![1, 2, 1], vec![2, 1, 2], vec![2, 2, 1], vec![2, 2, 2]]
+        );
+
+        game.state.pieces.remove(&vec![2, 2, 2]);
+        game.state.pieces.remove(&vec![1, 2, 1]);
+        game.state.current_turn = 1;
+        assert_eq!(
+            targets(&mut game, vec![2, 3, 3]),
+            vec![vec![2, 2, 3], vec![2, 3, 2]]
+        );
+    }
+
+    #[test]
+    fn test_pawn_promotes_on_last_rank_of_last_level() {
+        let mut game = load_raumschach();
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 3, 4], "PAWN", "WHITE");
+        insert(&mut game, vec![4, 3, 3], "PAWN", "WHITE");
+        insert(&mut game, vec![2, 0, 0], "KING", "WHITE");
+        insert(&mut game, vec![2, 0, 2], "KING", "BLACK");
+
+        // The far rank of a lower level is not the end: moving there is a plain move.
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![4, 3, 3],
+        })
+        .unwrap();
+        game.transition(GameTransition::ExecuteMove {
+            position: vec![4, 4, 3],
+        })
+        .unwrap();
+        assert_eq!(game.state.phase, GamePhase::Idle);
+
+        game.state.current_turn = 0;
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![0, 3, 4],
+        })
+        .unwrap();
+        game.transition(GameTransition::ExecuteMove {
+            position: vec![0, 4, 4],
+        })
+        .unwrap();
+        match &game.state.phase {
+            GamePhase::Transforming { position, options } => {
+                assert_eq!(position, &vec![0, 4, 4]);
+                assert!(options.contains(&"UNICORN".to_string()));
+            }
+            phase => panic!("expected promotion, got {phase:?}"),
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Drops cover every empty cell of the cube
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_drops_cover_all_levels() {
+        let mut game = load_raumschach();
+        game.state.hand = HashMap::from([(
+            "WHITE".to_string(),
+            HashMap::from([("ROOK".to_string(), 1)]),
+        )]);
+
+        let squares = game.compute_drop_squares("ROOK", "WHITE");
+        assert_eq!(squares.len(), 125 - 40);
+        assert!(squares.contains_key(&vec![2, 2, 2]));
+        assert!(squares.contains_key(&vec![0, 4, 0]));
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    /// Empty 6×6 board. WHITE moves up, BLACK down and RED towards higher files.
+    fn load_game() -> Game {
+        parse_game_spec("./src/tests/regions/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load regions test spec")
+    }
+
+    /// Every square of the board that satisfies `condition` for `player`.
+    fn squares(game: &Game, condition: &str, player: &str) -> Vec<Position> {
+        let mut squares: Vec<Position> = (0..6u8)
+            .flat_map(|x| (0..6u8).map(move |y| vec![x, y]))
+            .filter(|pos| {
+                game.check_position_condition_for_player(pos, &condition.to_string(), player)
+            })
+            .collect();
+        squares.sort();
+        squares
+    }
+
+    fn line(axis: usize, value: u8) -> Vec<Position> {
+        (0..6u8)
+            .map(|i| {
+                if axis == 0 {
+                    vec![value, i]
+                } else {
+                    vec![i, value]
+                }
+            })
+            .collect()
+    }
+
+    // -----------------------------------------------------------------------
+    // Relative ranks
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_relative_rank_follows_player_direction() {
+        let game = load_game();
+
+        assert_eq!(squares(&game, "HOME", "WHITE"), line(1, 0));
+        assert_eq!(squares(&game, "HOME", "BLACK"), line(1, 5));
+        assert_eq!(squares(&game, "HOME", "RED"), line(0, 0));
+    }
+
+    #[test]
+    fn test_relative_rank_drives_promotion() {
+        let mut game = load_game();
+        game.state.pieces.insert(
+            vec![2, 4],
+            Piece::new("PAWN".to_string(), "WHITE".to_string()),
+        );
+        game.state.pieces.insert(
+            vec![3, 1],
+            Piece::new("PAWN".to_string(), "BLACK".to_string()),
+        );
+
+        let white_promotion = Ply::Move {
+            from: vec![2, 4],
+            to: vec![2, 5],
+            promotion: Some("QUEEN".to_string()),
+        };
+        assert!(game.legal_plies().contains(&white_promotion));
+        assert!(game.play(&white_promotion).is_ok());
+
+        let black_promotion = Ply::Move {
+            from: vec![3, 1],
+            to: vec![3, 0],
+            promotion: Some("QUEEN".to_string()),
+        };
+        assert!(game.legal_plies().contains(&black_promotion));
+    }
+
+    // -----------------------------------------------------------------------
+    // Absolute regions
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_union_of_ranks_and_files() {
+        let game = load_game();
+        let edges = squares(&game, "EDGES", "WHITE");
+
+        assert_eq!(edges.len(), 20);
+        assert!(edges.contains(&vec![0, 3]));
+        assert!(edges.contains(&vec![3, 5]));
+        assert!(!edges.contains(&vec![1, 1]));
+    }
+
+    #[test]
+    fn test_difference_of_rectangles() {
+        let game = load_game();
+        let ring = squares(&game, "RING", "WHITE");
+
+        // A 4×4 square, given by its corners in any order, without its 2×2 centre.
+        assert_eq!(ring.len(), 12);
+        assert!(ring.contains(&vec![1, 1]));
+        assert!(ring.contains(&vec![4, 2]));
+        assert!(!ring.contains(&vec![2, 2]));
+        assert!(!ring.contains(&vec![0, 0]));
+    }
+
+    #[test]
+    fn test_regions_mix_with_single_squares() {
+        let game = load_game();
+        let mut expected = line(0, 3);
+        expected.insert(0, vec![0, 0]);
+
+        assert_eq!(squares(&game, "MIXED", "WHITE"), expected);
+        assert!(squares(&game, "MIXED", "BLACK").is_empty());
+    }
+}
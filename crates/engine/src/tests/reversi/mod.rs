@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GamePhase, Piece, Ply};
+    use crate::shared::{Position, UNLIMITED};
+    use crate::specs::parse_game_spec;
+
+    fn load_reversi() -> Game {
+        parse_game_spec("./specs/reversi.json")
+            .map(Game::from_spec)
+            .expect("Failed to load reversi spec")
+    }
+
+    fn insert(game: &mut Game, pos: Position, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new("STONE".to_string(), player.to_string()));
+    }
+
+    fn stone_at(game: &Game, pos: Position) -> Option<&str> {
+        game.state.pieces.get(&pos).map(|p| p.player.as_str())
+    }
+
+    fn drop(to: Position) -> Ply {
+        Ply::Drop {
+            piece_code: "STONE".to_string(),
+            to,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Opening: only flipping drops are legal
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_opening_drops_must_flip() {
+        let game = load_reversi();
+
+        assert_eq!(
+            game.legal_plies(),
+            vec![
+                drop(vec![2, 3]),
+                drop(vec![3, 2]),
+                drop(vec![4, 5]),
+                drop(vec![5, 4]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drop_flips_bounded_run_and_keeps_unlimited_hand() {
+        let mut game = load_reversi();
+
+        game.play(&drop(vec![2, 3])).unwrap();
+
+        assert_eq!(stone_at(&game, vec![2, 3]), Some("BLACK"));
+        assert_eq!(stone_at(&game, vec![3, 3]), Some("BLACK"));
+        assert_eq!(stone_at(&game, vec![4, 4]), Some("WHITE"));
+        assert_eq!(game.state.hand["BLACK"]["STONE"], UNLIMITED);
+        assert_eq!(game.current_player(), "WHITE");
+    }
+
+    #[test]
+    fn test_drop_flips_are_tallied_and_fill_the_hand() {
+        let mut game = load_reversi();
+        game.state
+            .hand
+            .get_mut("BLACK")
+            .unwrap()
+            .insert("STONE".to_string(), 5);
+
+        game.play(&drop(vec![2, 3])).unwrap();
+
+        // One stone flipped: it joins the hand as a capture would, and the drop uses one.
+        assert_eq!(game.state.captures["BLACK"]["STONE"], 1);
+        assert_eq!(game.state.hand["BLACK"]["STONE"], 5);
+    }
+
+    // -----------------------------------------------------------------------
+    // Flips run in all eight directions, and only up to the first ally
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_flips_in_every_direction() {
+        let mut game = load_reversi();
+        game.state.pieces.clear();
+        // A white ring around [3,3], each run closed by a black stone.
+        for (dx, dy) in [
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ] {
+            insert(&mut game, vec![(3 + dx) as u8, (3 + dy) as u8], "WHITE");
+            insert(
+                &mut game,
+                vec![(3 + 2 * dx) as u8, (3 + 2 * dy) as u8],
+                "BLACK",
+            );
+        }
+        // A run that is not closed by an ally is not flipped.
+        game.state.pieces.remove(&vec![5, 3]);
+
+        game.play(&drop(vec![3, 3])).unwrap();
+
+        let white: Vec<&Position> = game
+            .state
+            .pieces
+            .iter()
+            .filter(|(_, p)| p.player == "WHITE")
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(white, vec![&vec![4, 3]], "only the open run stays white");
+    }
+
+    // -----------------------------------------------------------------------
+    // Pass rule and majority scoring
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_player_without_a_flip_passes() {
+        let mut game = load_reversi();
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "BLACK");
+        insert(&mut game, vec![1, 0], "WHITE");
+        insert(&mut game, vec![3, 0], "WHITE");
+        insert(&mut game, vec![4, 0], "WHITE");
+
+        // After this, WHITE has nothing to flip, so BLACK moves again.
+        game.play(&drop(vec![2, 0])).unwrap();
+
+        assert_eq!(stone_at(&game, vec![1, 0]), Some("BLACK"));
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.current_player(), "BLACK");
+    }
+
+    #[test]
+    fn test_majority_wins_when_nobody_can_move() {
+        let mut game = load_reversi();
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "BLACK");
+        insert(&mut game, vec![1, 0], "BLACK");
+        insert(&mut game, vec![2, 0], "WHITE");
+
+        game.play(&drop(vec![3, 0])).unwrap();
+
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("BLACK".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_tied_count_is_a_draw() {
+        let mut game = load_reversi();
+        game.state.pieces.clear();
+        insert(&mut game, vec![0, 0], "BLACK");
+        insert(&mut game, vec![7, 7], "WHITE");
+
+        game.check_game_over();
+
+        assert_eq!(game.state.phase, GamePhase::GameOver { winner: None });
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, Ply};
+    use crate::specs::parse_game_spec;
+
+    /// 6×6 board: a WHITE rifle on [2,0] slides like a rook and shoots the BLACK pawn on [2,4]
+    /// instead of capturing it by displacement.
+    fn load_game() -> Game {
+        parse_game_spec("./src/tests/shooting/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load shooting test spec")
+    }
+
+    fn shot() -> Ply {
+        Ply::Move {
+            from: vec![2, 0],
+            to: vec![2, 4],
+            promotion: None,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // The shooter stays put
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_shot_removes_target_and_keeps_shooter() {
+        let mut game = load_game();
+
+        game.play(&shot()).unwrap();
+
+        assert!(game.piece_at_position(&vec![2, 4]).is_none());
+        let rifle = game.piece_at_position(&vec![2, 0]).unwrap();
+        assert_eq!(rifle.code, "RIFLE");
+        assert_eq!(rifle.total_moves, 1);
+        assert_eq!(game.state.captures["WHITE"]["PAWN"], 1);
+    }
+
+    #[test]
+    fn test_shot_is_recorded_from_shooter_to_target() {
+        let mut game = load_game();
+
+        game.play(&shot()).unwrap();
+
+        let record = game.state.history.last().unwrap();
+        assert_eq!(record.from, vec![2, 0]);
+        assert_eq!(record.to, vec![2, 4]);
+        assert_eq!(record.action, "SHOOT");
+    }
+
+    #[test]
+    fn test_shot_piece_enters_the_hand() {
+        let mut game = load_game();
+        game.hand_enabled = true;
+
+        game.play(&shot()).unwrap();
+
+        assert_eq!(game.state.hand["WHITE"]["PAWN"], 1);
+    }
+
+    // -----------------------------------------------------------------------
+    // Threats
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_shooter_attacks_along_its_line() {
+        let mut game = load_game();
+        assert_eq!(game.attackers_of(&vec![2, 4], "WHITE"), vec![vec![2, 0]]);
+
+        // With the pawn gone, the black king may not step into the line of fire.
+        game.state.pieces.remove(&vec![2, 4]);
+        let king = game.state.pieces.remove(&vec![5, 5]).unwrap();
+        game.state.pieces.insert(vec![3, 5], king);
+        game.play(&Ply::Move {
+            from: vec![0, 0],
+            to: vec![0, 1],
+            promotion: None,
+        })
+        .unwrap();
+
+        let plies = game.legal_plies();
+        assert!(!plies.contains(&Ply::Move {
+            from: vec![3, 5],
+            to: vec![2, 5],
+            promotion: None,
+        }));
+        assert!(plies.contains(&Ply::Move {
+            from: vec![3, 5],
+            to: vec![4, 5],
+            promotion: None,
+        }));
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    fn load_chess() -> Game {
+        let mut game = parse_game_spec("./specs/chess.json")
+            .map(Game::from_spec)
+            .expect("Failed to load chess spec");
+        game.state.pieces.clear();
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    // -----------------------------------------------------------------------
+    // A slide clears its origin square
+    //
+    // Every step of a repeated move starts from the square the previous step
+    // reached, but the board change must clear the square the piece left, not
+    // the step before the target. Otherwise a copy of the piece stays behind.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_slide_leaves_no_piece_behind() {
+        let mut game = load_chess();
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![4, 7], "KING", "BLACK");
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+
+        game.play(&Ply::Move {
+            from: vec![0, 0],
+            to: vec![0, 5],
+            promotion: None,
+        })
+        .unwrap();
+
+        let rooks: Vec<&Position> = game
+            .state
+            .pieces
+            .iter()
+            .filter(|(_, p)| p.code == "ROOK")
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(rooks, vec![&vec![0, 5]]);
+        assert_eq!(game.state.pieces.len(), 3);
+    }
+
+    #[test]
+    fn test_slide_effect_clears_only_the_origin() {
+        let mut game = load_chess();
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![4, 7], "KING", "BLACK");
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+
+        let rook = game.state.pieces[&vec![0, 0]].clone();
+        let moves = game.blueprints["ROOK"]
+            .calculate_moves(&rook, &vec![0, 0], &game)
+            .unwrap();
+        let cleared: Vec<&Position> = moves[&vec![0, 3]]
+            .board_changes
+            .iter()
+            .filter(|change| change.piece.is_none())
+            .map(|change| &change.position)
+            .collect();
+        assert_eq!(cleared, vec![&vec![0, 0]]);
+    }
+}
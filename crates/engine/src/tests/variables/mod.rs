@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GamePhase, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    /// Empty 6×6 board; BLACK only has a walker on [5,5] so it always has a reply.
+    fn load_game() -> Game {
+        let mut game = parse_game_spec("./src/tests/variables/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load variables test spec");
+        insert(&mut game, vec![5, 5], "WALKER", "BLACK");
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    fn drop_stone(to: Position) -> Ply {
+        Ply::Drop {
+            piece_code: "STONE".to_string(),
+            to,
+        }
+    }
+
+    /// Plays a quiet BLACK reply, shuffling the walker along the top rank.
+    fn black_waits(game: &mut Game) {
+        let (from, to) = if game.piece_at_position(&vec![5, 5]).is_some() {
+            (vec![5, 5], vec![4, 5])
+        } else {
+            (vec![4, 5], vec![5, 5])
+        };
+        game.play(&step(from, to)).unwrap();
+    }
+
+    /// WHITE collector on [0,0] with two BLACK dummies worth 5 points each in front of it.
+    fn collector_game() -> Game {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "COLLECTOR", "WHITE");
+        insert(&mut game, vec![0, 1], "DUMMY", "BLACK");
+        insert(&mut game, vec![0, 2], "DUMMY", "BLACK");
+        game
+    }
+
+    // -----------------------------------------------------------------------
+    // Per-player variables
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_drop_restriction_reads_drops_used() {
+        let mut game = load_game();
+
+        game.play(&drop_stone(vec![0, 0])).unwrap();
+        black_waits(&mut game);
+        game.play(&drop_stone(vec![1, 0])).unwrap();
+        black_waits(&mut game);
+
+        assert_eq!(game.variable("DROPS", None, "WHITE"), 2);
+        assert!(!game
+            .legal_plies()
+            .iter()
+            .any(|ply| matches!(ply, Ply::Drop { .. })));
+    }
+
+    #[test]
+    fn test_score_reaching_threshold_wins() {
+        let mut game = collector_game();
+
+        game.play(&step(vec![0, 0], vec![0, 1])).unwrap();
+        assert_eq!(game.variable("SCORE", Some("PLAYER"), "WHITE"), 5);
+        assert_eq!(game.variable("SCORE", None, "BLACK"), 0);
+        black_waits(&mut game);
+
+        game.play(&step(vec![0, 1], vec![0, 2])).unwrap();
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Global variables
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_global_variable_is_shared_by_both_players() {
+        let mut game = load_game();
+        insert(&mut game, vec![0, 0], "RINGER", "WHITE");
+        insert(&mut game, vec![0, 5], "RINGER", "BLACK");
+        insert(&mut game, vec![3, 3], "SILENCER", "BLACK");
+
+        // Once WHITE rings the bell, neither ringer may move until it is silenced.
+        game.play(&step(vec![0, 0], vec![1, 0])).unwrap();
+        assert_eq!(game.state.variables["BELL"], 1);
+        assert_eq!(game.variable("BELL", None, "BLACK"), 0);
+        assert!(!game.legal_plies().contains(&step(vec![0, 5], vec![1, 5])));
+
+        game.play(&step(vec![3, 3], vec![3, 4])).unwrap();
+        assert!(game.state.variables.is_empty());
+        assert!(game.legal_plies().contains(&step(vec![1, 0], vec![2, 0])));
+    }
+
+    // -----------------------------------------------------------------------
+    // Serialization and repetition
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_variables_survive_save_and_enter_position_key() {
+        let mut game = collector_game();
+        game.play(&step(vec![0, 0], vec![0, 1])).unwrap();
+
+        let json = game.save_state().unwrap();
+        let mut restored = load_game();
+        restored.restore_state(&json).unwrap();
+        assert_eq!(restored.variable("SCORE", None, "WHITE"), 5);
+
+        assert!(game
+            .state
+            .position_hashes
+            .last()
+            .is_some_and(|key| key.ends_with("WHITE:SCORE=5")));
+    }
+}
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::logic::{Game, GamePhase, GameTransition, Piece};
-    use crate::specs::parse_game_spec;
+    use crate::logic::{Game, GamePhase, GameTransition, Piece, Ply};
+    use crate::shared::Position;
+    use crate::specs::{parse_game_spec, WinConditionSpec};
 
     fn load_koth() -> Game {
         parse_game_spec("./specs/king_of_the_hill.json")
@@ -21,6 +22,26 @@ mod tests {
             .expect("Failed to load three_check spec")
     }
 
+    /// Chess without check, ending only through `win_condition`.
+    fn load_bare_chess(spec: &str, win_condition: &str) -> Game {
+        let mut game = parse_game_spec(spec)
+            .map(Game::from_spec)
+            .expect("Failed to load chess spec");
+        game.state.pieces.clear();
+        game.leader.clear();
+        game.win_conditions = vec![serde_json::from_str::<WinConditionSpec>(win_condition)
+            .expect("Failed to parse win condition")];
+        game
+    }
+
+    fn step(from: Position, to: Position) -> Ply {
+        Ply::Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
     fn insert(game: &mut Game, pos: Vec<u8>, code: &str, player: &str) {
         game.state
             .pieces
@@ -274,4 +295,128 @@ mod tests {
             "WHITE's check count should be 1 after the first delivered check"
         );
     }
+
+    // -----------------------------------------------------------------------
+    // OWN_BARE: losing every piece wins (antichess)
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_own_bare_awards_the_bare_player() {
+        let mut game = load_bare_chess("./specs/chess.json", r#"{ "type": "OWN_BARE" }"#);
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![1, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 5], "ROOK", "BLACK");
+
+        // WHITE takes BLACK's last piece, which hands BLACK the win.
+        game.play(&step(vec![0, 0], vec![0, 5])).unwrap();
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("BLACK".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_own_bare_respects_exempt_pieces() {
+        let mut game = load_bare_chess(
+            "./specs/chess.json",
+            r#"{ "type": "OWN_BARE", "exempt": ["KING"] }"#,
+        );
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 5], "ROOK", "BLACK");
+        insert(&mut game, vec![4, 4], "KNIGHT", "BLACK");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![0, 5])).unwrap();
+        assert_eq!(
+            game.state.phase,
+            GamePhase::Idle,
+            "BLACK still has a knight"
+        );
+
+        game.play(&step(vec![4, 4], vec![2, 5])).unwrap();
+        game.play(&step(vec![0, 5], vec![2, 5])).unwrap();
+
+        // Only the exempt king is left.
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("BLACK".to_string())
+            }
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // EXTINCTION: losing every piece of a listed code loses
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_extinction_of_a_listed_code_loses() {
+        let mut game = load_bare_chess(
+            "./specs/chess.json",
+            r#"{ "type": "EXTINCTION", "pieces": ["KING", "KNIGHT"] }"#,
+        );
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![4, 0], "KING", "WHITE");
+        insert(&mut game, vec![1, 0], "KNIGHT", "WHITE");
+        insert(&mut game, vec![0, 5], "KNIGHT", "BLACK");
+        insert(&mut game, vec![7, 7], "KING", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![0, 5])).unwrap();
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_extinction_eliminates_only_the_extinct_player() {
+        let mut game = load_bare_chess(
+            "./specs/4player_chess.json",
+            r#"{ "type": "EXTINCTION", "pieces": ["QUEEN"] }"#,
+        );
+        insert(&mut game, vec![0, 0], "QUEEN", "WHITE");
+        insert(&mut game, vec![7, 7], "QUEEN", "BLACK");
+        insert(&mut game, vec![0, 5], "QUEEN", "SILVER");
+        insert(&mut game, vec![7, 0], "QUEEN", "GOLD");
+
+        game.play(&step(vec![0, 0], vec![0, 5])).unwrap();
+
+        assert_eq!(game.state.phase, GamePhase::Idle);
+        assert_eq!(game.turn_order, vec!["WHITE", "BLACK", "GOLD"]);
+        assert_eq!(game.current_player(), "BLACK");
+    }
+
+    // -----------------------------------------------------------------------
+    // CAPTURE_COUNT: capturing N pieces wins
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_capture_count_of_a_code() {
+        let mut game = load_bare_chess(
+            "./specs/chess.json",
+            r#"{ "type": "CAPTURE_COUNT", "piece": "PAWN", "threshold": 2 }"#,
+        );
+        insert(&mut game, vec![0, 0], "ROOK", "WHITE");
+        insert(&mut game, vec![0, 3], "PAWN", "BLACK");
+        insert(&mut game, vec![5, 3], "PAWN", "BLACK");
+        insert(&mut game, vec![7, 7], "KNIGHT", "BLACK");
+
+        game.play(&step(vec![0, 0], vec![0, 3])).unwrap();
+        assert_eq!(game.state.captures["WHITE"]["PAWN"], 1);
+        assert_eq!(game.state.phase, GamePhase::Idle);
+
+        // Knights do not count towards a pawn tally.
+        game.play(&step(vec![7, 7], vec![6, 5])).unwrap();
+        game.play(&step(vec![0, 3], vec![5, 3])).unwrap();
+        assert_eq!(
+            game.state.phase,
+            GamePhase::GameOver {
+                winner: Some("WHITE".to_string())
+            }
+        );
+    }
 }
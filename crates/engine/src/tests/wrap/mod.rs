@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use crate::logic::{Game, GameTransition, Piece};
+    use crate::shared::Position;
+    use crate::specs::parse_game_spec;
+
+    fn load_torus() -> Game {
+        let mut game = parse_game_spec("./src/tests/wrap/spec.json")
+            .map(Game::from_spec)
+            .expect("Failed to load wrap test spec");
+        game.state.pieces.clear();
+        game
+    }
+
+    fn insert(game: &mut Game, pos: Position, code: &str, player: &str) {
+        game.state
+            .pieces
+            .insert(pos, Piece::new(code.to_string(), player.to_string()));
+    }
+
+    fn targets(game: &mut Game, pos: Position) -> Vec<Position> {
+        game.transition(GameTransition::CalculateMoves { position: pos })
+            .unwrap();
+        let mut targets: Vec<Position> = game
+            .state
+            .available_moves
+            .as_ref()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        targets.sort();
+        targets
+    }
+
+    // -----------------------------------------------------------------------
+    // Sliding across the edges
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_rook_slides_across_both_edges() {
+        let mut game = load_torus();
+        insert(&mut game, vec![0, 3], "ROOK", "WHITE");
+        insert(&mut game, vec![2, 3], "STONE", "WHITE");
+        insert(&mut game, vec![0, 5], "STONE", "BLACK");
+
+        let targets = targets(&mut game, vec![0, 3]);
+
+        // Leftwards it reappears on the right edge and runs into its own stone.
+        assert!(targets.contains(&vec![7, 3]));
+        assert!(targets.contains(&vec![3, 3]));
+        assert!(!targets.contains(&vec![2, 3]));
+
+        // Downwards it wraps to the top rank and captures the enemy stone from above.
+        assert!(targets.contains(&vec![0, 0]));
+        assert!(targets.contains(&vec![0, 7]));
+        assert!(targets.contains(&vec![0, 5]));
+        assert_eq!(targets.len(), 1 + 5 + 2 + 5);
+    }
+
+    #[test]
+    fn test_slide_stops_when_back_at_source() {
+        let mut game = load_torus();
+        insert(&mut game, vec![0, 0], "QUEEN", "WHITE");
+
+        // Every line on an empty torus loops back to the queen: 7 cells on each of the
+        // rank, file and two diagonals, where both diagonals meet again on [4, 4].
+        let targets = targets(&mut game, vec![0, 0]);
+        assert_eq!(targets.len(), 27);
+        assert!(!targets.contains(&vec![0, 0]));
+    }
+
+    // -----------------------------------------------------------------------
+    // Path conditions and threats
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_path_piece_count_across_edge() {
+        let mut game = load_torus();
+        insert(&mut game, vec![6, 0], "CANNON", "WHITE");
+        insert(&mut game, vec![7, 0], "STONE", "WHITE");
+        insert(&mut game, vec![1, 0], "STONE", "BLACK");
+
+        // The screen on [7, 0] sits between the cannon and its target on the far side.
+        assert_eq!(targets(&mut game, vec![6, 0]), vec![vec![1, 0]]);
+
+        // With a second piece in the way the capture is gone.
+        insert(&mut game, vec![0, 0], "STONE", "WHITE");
+        game.transition(GameTransition::CalculateMoves {
+            position: vec![6, 0],
+        })
+        .unwrap();
+        assert!(game.state.available_moves.is_none());
+    }
+
+    #[test]
+    fn test_threats_wrap() {
+        let mut game = load_torus();
+        insert(&mut game, vec![0, 3], "ROOK", "WHITE");
+        insert(&mut game, vec![7, 7], "STONE", "BLACK");
+
+        let attacked = game.attacked_by("WHITE");
+        assert!(attacked.contains(&vec![7, 3]));
+        assert!(attacked.contains(&vec![0, 7]));
+        assert_eq!(attacked.len(), 14);
+    }
+}